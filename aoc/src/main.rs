@@ -0,0 +1,303 @@
+use std::env;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use anyhow::{bail, Context, Result};
+use clap::{Parser, Subcommand};
+
+const CARGO_TEMPLATE: &str = include_str!("../templates/Cargo.toml.template");
+const LIB_TEMPLATE: &str = include_str!("../templates/lib.rs.template");
+const MAIN_TEMPLATE: &str = include_str!("../templates/main.rs.template");
+
+const SOLUTIONS_URL: &str = "https://github.com/scottroemeschke/aoc2021/tree/main";
+
+#[derive(Parser, Debug)]
+#[command(about = "Workspace chores for the Advent of Code solutions")]
+struct Args {
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Subcommand, Debug)]
+enum Command {
+    /// Start a new day: create its crate, add it to the workspace and list it in the README
+    New {
+        /// The day of the puzzle, 1 to 25
+        #[arg(value_parser = clap::value_parser!(u8).range(1..=25))]
+        day: u8,
+
+        /// The puzzle's title, e.g. "Hydrothermal Venture"
+        #[arg(long)]
+        title: Option<String>,
+
+        /// The workspace to add the day to, found by searching up from the working directory if
+        /// not given
+        #[arg(long)]
+        root: Option<PathBuf>,
+    },
+}
+
+fn main() -> Result<()> {
+    let args = Args::parse();
+    match args.command {
+        Command::New { day, title, root } => {
+            let root = match root {
+                Some(root) => root,
+                None => find_workspace_root(&env::current_dir()?)?,
+            };
+            let title = title.unwrap_or_else(|| format!("Day {}", day));
+            let crate_name = new_day(&root, day, &title)?;
+            println!(
+                "Created {}, paste the example into {} and the puzzle input into {}",
+                crate_name,
+                root.join(&crate_name).join("example").display(),
+                root.join(&crate_name).join("input").display()
+            );
+        }
+    }
+    Ok(())
+}
+
+/// The nearest directory at or above `start` with a workspace `Cargo.toml`.
+fn find_workspace_root(start: &Path) -> Result<PathBuf> {
+    for dir in start.ancestors() {
+        let manifest = dir.join("Cargo.toml");
+        if let Ok(contents) = fs::read_to_string(&manifest) {
+            if contents.lines().any(|line| line.trim() == "[workspace]") {
+                return Ok(dir.to_path_buf());
+            }
+        }
+    }
+    bail!("no workspace Cargo.toml at or above {}", start.display())
+}
+
+/// Creates the day's crate under `root`, returning its name.
+fn new_day(root: &Path, day: u8, title: &str) -> Result<String> {
+    let crate_name = format!("day-{}", number_name(day)?);
+    let crate_dir = root.join(&crate_name);
+    if crate_dir.exists() {
+        bail!("{} already exists", crate_dir.display());
+    }
+
+    let manifest_path = root.join("Cargo.toml");
+    let manifest = fs::read_to_string(&manifest_path)
+        .with_context(|| format!("failed to read {}", manifest_path.display()))?;
+    let manifest = add_workspace_member(&manifest, &crate_name)?;
+    let readme_path = root.join("README.md");
+    let readme = fs::read_to_string(&readme_path)
+        .with_context(|| format!("failed to read {}", readme_path.display()))?;
+    let readme = add_readme_entry(&readme, day, title, &crate_name);
+
+    let fill = |template: &str| {
+        template
+            .replace("{{crate_name}}", &crate_name)
+            .replace("{{lib_name}}", &crate_name.replace('-', "_"))
+            .replace("{{struct_name}}", &struct_name(&crate_name))
+            .replace("{{day}}", &day.to_string())
+            .replace("{{title}}", title)
+    };
+    fs::create_dir_all(crate_dir.join("src"))?;
+    fs::write(crate_dir.join("Cargo.toml"), fill(CARGO_TEMPLATE))?;
+    fs::write(crate_dir.join("src").join("lib.rs"), fill(LIB_TEMPLATE))?;
+    fs::write(crate_dir.join("src").join("main.rs"), fill(MAIN_TEMPLATE))?;
+    fs::write(crate_dir.join("example"), "")?;
+
+    fs::write(&manifest_path, manifest)?;
+    fs::write(&readme_path, readme)?;
+    Ok(crate_name)
+}
+
+/// The crates are named `day-one` to `day-twenty-five`.
+fn number_name(number: u8) -> Result<String> {
+    const ONES: [&str; 20] = [
+        "zero",
+        "one",
+        "two",
+        "three",
+        "four",
+        "five",
+        "six",
+        "seven",
+        "eight",
+        "nine",
+        "ten",
+        "eleven",
+        "twelve",
+        "thirteen",
+        "fourteen",
+        "fifteen",
+        "sixteen",
+        "seventeen",
+        "eighteen",
+        "nineteen",
+    ];
+    Ok(match number {
+        1..=19 => ONES[number as usize].to_string(),
+        20 => "twenty".to_string(),
+        21..=25 => format!("twenty-{}", ONES[number as usize - 20]),
+        _ => bail!("days go from 1 to 25, found {}", number),
+    })
+}
+
+/// `day-twenty-one` to `DayTwentyOne`.
+fn struct_name(crate_name: &str) -> String {
+    crate_name
+        .split('-')
+        .map(|word| {
+            let mut chars = word.chars();
+            chars
+                .next()
+                .map(|first| first.to_ascii_uppercase().to_string() + chars.as_str())
+                .unwrap_or_default()
+        })
+        .collect()
+}
+
+/// Adds the crate to the end of the `members` list, rewriting it one member per line.
+fn add_workspace_member(manifest: &str, crate_name: &str) -> Result<String> {
+    let start = match manifest.find("members") {
+        Some(start) => start,
+        None => bail!("the workspace Cargo.toml has no members list"),
+    };
+    let open = start
+        + manifest[start..]
+            .find('[')
+            .context("members isn't a list")?;
+    let close = open
+        + manifest[open..]
+            .find(']')
+            .context("members list isn't closed")?;
+
+    let mut members = manifest[open + 1..close]
+        .split(',')
+        .map(|member| member.trim().trim_matches('"'))
+        .filter(|member| !member.is_empty())
+        .collect::<Vec<&str>>();
+    if members.contains(&crate_name) {
+        bail!("{} is already a workspace member", crate_name);
+    }
+    members.push(crate_name);
+
+    let list = members
+        .iter()
+        .map(|member| format!("    \"{}\",\n", member))
+        .collect::<String>();
+    Ok(format!(
+        "{}[\n{}{}",
+        &manifest[..open],
+        list,
+        &manifest[close..]
+    ))
+}
+
+/// Appends the day to the end of the README's "Days" section.
+fn add_readme_entry(readme: &str, day: u8, title: &str, crate_name: &str) -> String {
+    let entry = format!(
+        "### Day {day}\n\n[AOC: {title}](https://adventofcode.com/2021/day/{day})\n\n[Day {day} Solution]({url}/{crate_name})\n",
+        day = day,
+        title = title,
+        url = SOLUTIONS_URL,
+        crate_name = crate_name
+    );
+
+    //the section runs to the next heading of the same level, or the end of the file
+    let section_end = readme.find("## Days").and_then(|days| {
+        readme[days + "## Days".len()..]
+            .find("\n## ")
+            .map(|next| days + "## Days".len() + next + 1)
+    });
+    match section_end {
+        Some(end) => format!(
+            "{}\n\n{}\n{}",
+            readme[..end].trim_end(),
+            entry,
+            &readme[end..]
+        ),
+        None if readme.contains("## Days") => format!("{}\n\n{}", readme.trim_end(), entry),
+        None => format!("{}\n\n## Days\n\n{}", readme.trim_end(), entry),
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::fs;
+
+    use crate::{add_readme_entry, add_workspace_member, new_day, number_name, struct_name};
+
+    #[test]
+    fn test_names() {
+        assert_eq!(number_name(5).unwrap(), "five");
+        assert_eq!(number_name(13).unwrap(), "thirteen");
+        assert_eq!(number_name(20).unwrap(), "twenty");
+        assert_eq!(number_name(25).unwrap(), "twenty-five");
+        assert!(number_name(0).is_err());
+        assert!(number_name(26).is_err());
+        assert_eq!(struct_name("day-twenty-one"), "DayTwentyOne");
+    }
+
+    #[test]
+    fn test_add_workspace_member() {
+        let manifest =
+            "[workspace]\nresolver = \"2\"\n\nmembers = [\n    \"common\",\n    \"day-one\"\n]\n";
+        assert_eq!(
+            add_workspace_member(manifest, "day-two").unwrap(),
+            "[workspace]\nresolver = \"2\"\n\nmembers = [\n    \"common\",\n    \"day-one\",\n    \"day-two\",\n]\n"
+        );
+        assert!(add_workspace_member(manifest, "day-one").is_err());
+        assert!(add_workspace_member("[workspace]\n", "day-one").is_err());
+    }
+
+    #[test]
+    fn test_add_readme_entry() {
+        let readme = "# AoC\n\n## Days\n   \n### Day 1\n\nday one\n";
+        assert_eq!(
+            add_readme_entry(readme, 2, "Dive!", "day-two"),
+            "# AoC\n\n## Days\n   \n### Day 1\n\nday one\n\n### Day 2\n\n[AOC: Dive!](https://adventofcode.com/2021/day/2)\n\n[Day 2 Solution](https://github.com/scottroemeschke/aoc2021/tree/main/day-two)\n"
+        );
+
+        //a later section stays after the new entry
+        let readme = "## Days\n\n### Day 1\n\n## Notes\n\nnotes\n";
+        let updated = add_readme_entry(readme, 2, "Dive!", "day-two");
+        assert!(updated.contains("### Day 1\n\n### Day 2\n"));
+        assert!(updated.ends_with("day-two)\n\n## Notes\n\nnotes\n"));
+    }
+
+    #[test]
+    fn test_new_day() {
+        let root = std::env::temp_dir().join(format!("aoc-new-day-{}", std::process::id()));
+        let _ = fs::remove_dir_all(&root);
+        fs::create_dir_all(&root).unwrap();
+        fs::write(
+            root.join("Cargo.toml"),
+            "[workspace]\n\nmembers = [\n    \"common\",\n]\n",
+        )
+        .unwrap();
+        fs::write(root.join("README.md"), "## Days\n").unwrap();
+
+        assert_eq!(
+            new_day(&root, 5, "Hydrothermal Venture").unwrap(),
+            "day-five"
+        );
+        let lib = fs::read_to_string(root.join("day-five/src/lib.rs")).unwrap();
+        assert!(lib.contains("pub struct DayFive;"));
+        assert!(lib.contains("\"Day 5: Hydrothermal Venture\""));
+        assert!(!lib.contains("{{"));
+        assert!(!lib.contains("todo!"));
+        let main = fs::read_to_string(root.join("day-five/src/main.rs")).unwrap();
+        assert!(main.contains("use day_five::DayFive;"));
+        assert!(fs::read_to_string(root.join("day-five/Cargo.toml"))
+            .unwrap()
+            .contains("name = \"day-five\""));
+        assert!(root.join("day-five/example").exists());
+        assert!(fs::read_to_string(root.join("Cargo.toml"))
+            .unwrap()
+            .contains("\"day-five\","));
+        assert!(fs::read_to_string(root.join("README.md"))
+            .unwrap()
+            .contains("### Day 5"));
+
+        //a second run doesn't clobber the first
+        assert!(new_day(&root, 5, "Hydrothermal Venture").is_err());
+        fs::remove_dir_all(&root).unwrap();
+    }
+}
@@ -0,0 +1,308 @@
+//! A fixed size, row-major 2D grid, for puzzles laid out as rows of cells.
+//!
+//! Cells are addressed as `(row, column)` from the top left. Every row has the same number of
+//! columns.
+
+use std::fmt;
+use std::ops::{Index, IndexMut};
+use std::str::FromStr;
+
+use anyhow::{bail, Result};
+
+use crate::parse::{whitespace_grid, ParseError};
+
+#[derive(Debug, PartialEq, Eq, Clone, Hash)]
+pub struct Grid<T> {
+    num_rows: usize,
+    num_columns: usize,
+    cells: Vec<T>,
+}
+
+impl<T> Grid<T> {
+    /// A grid from cells in row-major order, erroring if they don't fill the shape exactly.
+    pub fn from_vec(num_rows: usize, num_columns: usize, cells: Vec<T>) -> Result<Self> {
+        if cells.len() != num_rows * num_columns {
+            bail!(
+                "a {}x{} grid needs {} cells, found {}",
+                num_rows,
+                num_columns,
+                num_rows * num_columns,
+                cells.len()
+            );
+        }
+        Ok(Grid {
+            num_rows,
+            num_columns,
+            cells,
+        })
+    }
+
+    /// A grid from its rows, erroring if they aren't all the same length.
+    pub fn from_rows(rows: Vec<Vec<T>>) -> Result<Self> {
+        let num_rows = rows.len();
+        let num_columns = rows.first().map_or(0, Vec::len);
+        if let Some(row) = rows.iter().find(|row| row.len() != num_columns) {
+            bail!(
+                "grid rows must all be the same length, found a row of {} cells in a grid {} wide",
+                row.len(),
+                num_columns
+            );
+        }
+        Grid::from_vec(num_rows, num_columns, rows.into_iter().flatten().collect())
+    }
+
+    /// (rows, columns)
+    pub fn shape(&self) -> (usize, usize) {
+        (self.num_rows, self.num_columns)
+    }
+
+    pub fn num_rows(&self) -> usize {
+        self.num_rows
+    }
+
+    pub fn num_columns(&self) -> usize {
+        self.num_columns
+    }
+
+    pub fn get(&self, row: usize, column: usize) -> Option<&T> {
+        self.offset(row, column).map(|offset| &self.cells[offset])
+    }
+
+    pub fn get_mut(&mut self, row: usize, column: usize) -> Option<&mut T> {
+        self.offset(row, column)
+            .map(|offset| &mut self.cells[offset])
+    }
+
+    /// Every cell in row-major order.
+    pub fn iter(&self) -> impl Iterator<Item = &T> {
+        self.cells.iter()
+    }
+
+    pub fn iter_mut(&mut self) -> impl Iterator<Item = &mut T> {
+        self.cells.iter_mut()
+    }
+
+    /// Every cell with its `(row, column)`, in row-major order.
+    pub fn indexed_iter(&self) -> impl Iterator<Item = ((usize, usize), &T)> {
+        let num_columns = self.num_columns;
+        self.cells
+            .iter()
+            .enumerate()
+            .map(move |(offset, cell)| ((offset / num_columns, offset % num_columns), cell))
+    }
+
+    pub fn row(&self, row: usize) -> &[T] {
+        assert!(row < self.num_rows, "row {} out of bounds", row);
+        &self.cells[row * self.num_columns..(row + 1) * self.num_columns]
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        (0..self.num_rows).map(move |row| self.row(row))
+    }
+
+    /// The cells of a column, top to bottom.
+    pub fn column(&self, column: usize) -> impl Iterator<Item = &T> {
+        assert!(column < self.num_columns, "column {} out of bounds", column);
+        self.cells.iter().skip(column).step_by(self.num_columns)
+    }
+
+    pub fn columns(&self) -> impl Iterator<Item = impl Iterator<Item = &T>> {
+        (0..self.num_columns).map(move |column| self.column(column))
+    }
+
+    /// The cells up, left, right and down of a cell that are inside the grid.
+    pub fn neighbors(&self, row: usize, column: usize) -> impl Iterator<Item = (usize, usize)> {
+        const OFFSETS: [(isize, isize); 4] = [(-1, 0), (0, -1), (0, 1), (1, 0)];
+        self.offset_cells(row, column, &OFFSETS)
+    }
+
+    /// The neighbors including the diagonal ones, in reading order.
+    pub fn neighbors_with_diagonals(
+        &self,
+        row: usize,
+        column: usize,
+    ) -> impl Iterator<Item = (usize, usize)> {
+        const OFFSETS: [(isize, isize); 8] = [
+            (-1, -1),
+            (-1, 0),
+            (-1, 1),
+            (0, -1),
+            (0, 1),
+            (1, -1),
+            (1, 0),
+            (1, 1),
+        ];
+        self.offset_cells(row, column, &OFFSETS)
+    }
+
+    pub fn map<U>(&self, f: impl FnMut(&T) -> U) -> Grid<U> {
+        Grid {
+            num_rows: self.num_rows,
+            num_columns: self.num_columns,
+            cells: self.cells.iter().map(f).collect(),
+        }
+    }
+
+    fn offset(&self, row: usize, column: usize) -> Option<usize> {
+        (row < self.num_rows && column < self.num_columns).then(|| row * self.num_columns + column)
+    }
+
+    fn offset_cells(
+        &self,
+        row: usize,
+        column: usize,
+        offsets: &'static [(isize, isize)],
+    ) -> impl Iterator<Item = (usize, usize)> {
+        let (num_rows, num_columns) = self.shape();
+        offsets
+            .iter()
+            .filter_map(move |&(row_offset, column_offset)| {
+                let row = row.checked_add_signed(row_offset)?;
+                let column = column.checked_add_signed(column_offset)?;
+                (row < num_rows && column < num_columns).then_some((row, column))
+            })
+    }
+}
+
+impl<T: Clone> Grid<T> {
+    pub fn filled(num_rows: usize, num_columns: usize, value: T) -> Self {
+        Grid {
+            num_rows,
+            num_columns,
+            cells: vec![value; num_rows * num_columns],
+        }
+    }
+
+    /// Rows become columns.
+    pub fn transpose(&self) -> Self {
+        Grid {
+            num_rows: self.num_columns,
+            num_columns: self.num_rows,
+            cells: self.columns().flatten().cloned().collect(),
+        }
+    }
+}
+
+impl<T> Index<(usize, usize)> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, (row, column): (usize, usize)) -> &T {
+        match self.offset(row, column) {
+            Some(offset) => &self.cells[offset],
+            None => panic!(
+                "({}, {}) out of bounds of a {}x{} grid",
+                row, column, self.num_rows, self.num_columns
+            ),
+        }
+    }
+}
+
+impl<T> IndexMut<(usize, usize)> for Grid<T> {
+    fn index_mut(&mut self, (row, column): (usize, usize)) -> &mut T {
+        match self.offset(row, column) {
+            Some(offset) => &mut self.cells[offset],
+            None => panic!(
+                "({}, {}) out of bounds of a {}x{} grid",
+                row, column, self.num_rows, self.num_columns
+            ),
+        }
+    }
+}
+
+/// Rows on their own lines, cells separated by whitespace, see [`whitespace_grid`].
+impl<T> FromStr for Grid<T>
+where
+    T: FromStr,
+    T::Err: fmt::Display,
+{
+    type Err = ParseError;
+
+    fn from_str(text: &str) -> Result<Self, ParseError> {
+        whitespace_grid(text)
+    }
+}
+
+/// Cells right aligned in columns as wide as the widest cell, one row per line.
+impl<T: fmt::Display> fmt::Display for Grid<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let cells = self
+            .cells
+            .iter()
+            .map(ToString::to_string)
+            .collect::<Vec<String>>();
+        let width = cells.iter().map(|cell| cell.chars().count()).max();
+        let width = width.unwrap_or(0);
+        for row in cells.chunks(self.num_columns.max(1)) {
+            let row = row
+                .iter()
+                .map(|cell| format!("{:>width$}", cell, width = width))
+                .collect::<Vec<String>>();
+            writeln!(f, "{}", row.join(" "))?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::grid::Grid;
+
+    fn grid() -> Grid<u32> {
+        Grid::from_rows(vec![vec![1, 2, 3], vec![4, 5, 60]]).unwrap()
+    }
+
+    #[test]
+    fn test_rows_and_columns() {
+        let grid = grid();
+        assert_eq!(grid.shape(), (2, 3));
+        assert_eq!(grid[(1, 2)], 60);
+        assert_eq!(grid.get(2, 0), None);
+        assert_eq!(
+            grid.rows().collect::<Vec<_>>(),
+            vec![&[1, 2, 3], &[4, 5, 60]]
+        );
+        let columns = grid
+            .columns()
+            .map(|column| column.copied().collect())
+            .collect::<Vec<Vec<u32>>>();
+        assert_eq!(columns, vec![vec![1, 4], vec![2, 5], vec![3, 60]]);
+        assert_eq!(grid.indexed_iter().nth(4), Some(((1, 1), &5)));
+    }
+
+    #[test]
+    fn test_transpose() {
+        let transposed = grid().transpose();
+        assert_eq!(transposed.shape(), (3, 2));
+        assert_eq!(transposed.row(2), &[3, 60]);
+        assert_eq!(transposed.transpose(), grid());
+    }
+
+    #[test]
+    fn test_neighbors() {
+        let grid = grid();
+        assert_eq!(
+            grid.neighbors(0, 0).collect::<Vec<_>>(),
+            vec![(0, 1), (1, 0)]
+        );
+        assert_eq!(
+            grid.neighbors(1, 1).collect::<Vec<_>>(),
+            vec![(0, 1), (1, 0), (1, 2)]
+        );
+        assert_eq!(
+            grid.neighbors_with_diagonals(0, 1).collect::<Vec<_>>(),
+            vec![(0, 0), (0, 2), (1, 0), (1, 1), (1, 2)]
+        );
+    }
+
+    #[test]
+    fn test_parse_and_display() {
+        let parsed = "\n 1 2  3\n4 5 60\n".parse::<Grid<u32>>().unwrap();
+        assert_eq!(parsed, grid());
+        assert_eq!(parsed.to_string(), " 1  2  3\n 4  5 60\n");
+
+        let error = "1 2\n3 x".parse::<Grid<u32>>().unwrap_err();
+        assert!(error.to_string().starts_with("line 2:"), "{}", error);
+        let error = "1 2\n\n3 4 5".parse::<Grid<u32>>().unwrap_err();
+        assert!(error.to_string().starts_with("line 3:"), "{}", error);
+    }
+}
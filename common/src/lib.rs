@@ -1,7 +1,12 @@
 use std::{fs, env};
 use anyhow::{Context, Result};
 
+pub mod grid;
+pub mod parallel;
+pub mod parse;
+pub mod solution;
+
 pub fn read_input() -> Result<String> {
     fs::read_to_string("input")
         .with_context(|| format!("failed to read input file at working directory: {:?}", env::current_dir()))
-}
\ No newline at end of file
+}
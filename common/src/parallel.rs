@@ -0,0 +1,64 @@
+//! Chunked map/reduce over index ranges.
+//!
+//! With the `parallel` feature the chunks are spread across rayon's thread pool, without it they
+//! are walked in order on the current thread. Days opt in by forwarding their own `parallel`
+//! feature to `common/parallel`, the call sites stay the same either way.
+
+use std::ops::Range;
+
+#[cfg(feature = "parallel")]
+use rayon::prelude::*;
+
+/// Splits `0..len` into ranges of at most `chunk_size`, maps every range and reduces the results.
+///
+/// `reduce` must be associative and `identity` must be its identity, the chunks may be combined
+/// in any grouping when running in parallel.
+pub fn map_reduce_ranges<R, ID, M, F>(
+    len: usize,
+    chunk_size: usize,
+    identity: ID,
+    map: M,
+    reduce: F,
+) -> R
+where
+    R: Send,
+    ID: Fn() -> R + Sync + Send,
+    M: Fn(Range<usize>) -> R + Sync + Send,
+    F: Fn(R, R) -> R + Sync + Send,
+{
+    assert!(chunk_size > 0, "chunk size must be greater than zero");
+    let chunk = |i: usize| i * chunk_size..((i + 1) * chunk_size).min(len);
+    let num_chunks = len.div_ceil(chunk_size);
+
+    #[cfg(feature = "parallel")]
+    {
+        (0..num_chunks)
+            .into_par_iter()
+            .map(|i| map(chunk(i)))
+            .reduce(identity, reduce)
+    }
+
+    #[cfg(not(feature = "parallel"))]
+    {
+        (0..num_chunks).fold(identity(), |acc, i| reduce(acc, map(chunk(i))))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::parallel::map_reduce_ranges;
+
+    #[test]
+    fn test_map_reduce_ranges_covers_every_index_once() {
+        let numbers = (0..10_001u64).collect::<Vec<u64>>();
+        let sum = map_reduce_ranges(
+            numbers.len(),
+            64,
+            || 0,
+            |range| numbers[range].iter().sum::<u64>(),
+            |a, b| a + b,
+        );
+        assert_eq!(sum, numbers.iter().sum());
+        assert_eq!(map_reduce_ranges(0, 64, || 0, |r| r.len(), |a, b| a + b), 0);
+    }
+}
@@ -0,0 +1,321 @@
+//! Helpers for the shapes puzzle inputs come in: a value per line, blank line separated blocks, a
+//! comma separated list, a grid of whitespace separated values, a grid of digits and lines of bits.
+//!
+//! Lines are trimmed, blank lines are skipped (or separate blocks), and errors say which 1-based
+//! line was bad.
+
+use std::fmt;
+use std::str::FromStr;
+
+use crate::grid::Grid;
+
+/// What was wrong with which line of the input.
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct ParseError {
+    /// 1-based, counting blank lines too.
+    pub line: usize,
+    pub text: String,
+    pub reason: String,
+}
+
+impl ParseError {
+    pub fn new(line: usize, text: &str, reason: impl fmt::Display) -> Self {
+        ParseError {
+            line,
+            text: text.to_string(),
+            reason: reason.to_string(),
+        }
+    }
+
+    /// Moves the error down by `lines`, for when the text parsed started partway into the input.
+    pub fn offset_lines(self, lines: usize) -> Self {
+        ParseError {
+            line: self.line + lines,
+            ..self
+        }
+    }
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "line {}: {}, found {:?}",
+            self.line, self.reason, self.text
+        )
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+/// Blank line separated lines of the input, with the line number the block starts on.
+///
+/// Only [`blocks`] makes these, so there's always at least one line.
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct Block<'a> {
+    first_line: usize,
+    lines: Vec<&'a str>,
+}
+
+impl<'a> Block<'a> {
+    pub fn first_line(&self) -> usize {
+        self.first_line
+    }
+
+    pub fn lines(&self) -> &[&'a str] {
+        &self.lines
+    }
+
+    pub fn last_line(&self) -> usize {
+        self.first_line + self.lines.len() - 1
+    }
+
+    /// The lines with their line numbers in the whole input.
+    pub fn numbered_lines(&self) -> impl Iterator<Item = (usize, &'a str)> + '_ {
+        (self.first_line..).zip(self.lines.iter().copied())
+    }
+}
+
+/// The non-blank lines, trimmed, with their 1-based line numbers.
+pub fn numbered_lines(input: &str) -> impl Iterator<Item = (usize, &str)> {
+    input
+        .lines()
+        .enumerate()
+        .map(|(index, line)| (index + 1, line.trim()))
+        .filter(|(_, line)| !line.is_empty())
+}
+
+/// One value per line.
+pub fn lines_of<T>(input: &str) -> Result<Vec<T>, ParseError>
+where
+    T: FromStr,
+    T::Err: fmt::Display,
+{
+    numbered_lines(input)
+        .map(|(line, text)| text.parse().map_err(|e| ParseError::new(line, text, e)))
+        .collect()
+}
+
+/// Runs of non-blank lines, split wherever there are one or more blank lines.
+///
+/// Lines are kept as they are apart from a trailing `\r`, so indentation is left to the caller.
+pub fn blocks(input: &str) -> Vec<Block<'_>> {
+    let mut blocks = Vec::new();
+    let mut current: Option<Block> = None;
+    for (index, line) in input.lines().enumerate() {
+        //lines() strips CRLF endings itself, but not a lone \r at the very end of the input
+        let line = line.trim_end_matches('\r');
+        if line.trim().is_empty() {
+            blocks.extend(current.take());
+        } else {
+            current
+                .get_or_insert_with(|| Block {
+                    first_line: index + 1,
+                    lines: Vec::new(),
+                })
+                .lines
+                .push(line);
+        }
+    }
+    blocks.extend(current);
+    blocks
+}
+
+/// A single line of comma separated values, with any whitespace around the commas.
+pub fn comma_separated<T>(input: &str) -> Result<Vec<T>, ParseError>
+where
+    T: FromStr,
+    T::Err: fmt::Display,
+{
+    let mut lines = numbered_lines(input);
+    let (line, text) = lines
+        .next()
+        .ok_or_else(|| ParseError::new(1, input, "expected comma separated values"))?;
+    if let Some((extra_line, extra_text)) = lines.next() {
+        return Err(ParseError::new(
+            extra_line,
+            extra_text,
+            "expected the comma separated values on a single line",
+        ));
+    }
+
+    text.split(',')
+        .map(|value| {
+            value.trim().parse().map_err(|e| {
+                ParseError::new(
+                    line,
+                    text,
+                    format!("invalid value {:?}: {}", value.trim(), e),
+                )
+            })
+        })
+        .collect()
+}
+
+/// Rows of whitespace separated values, every row as wide as the first.
+pub fn whitespace_grid<T>(input: &str) -> Result<Grid<T>, ParseError>
+where
+    T: FromStr,
+    T::Err: fmt::Display,
+{
+    let mut rows = Vec::<Vec<T>>::new();
+    for (line, text) in numbered_lines(input) {
+        let row = text
+            .split_whitespace()
+            .map(|value| {
+                value.parse().map_err(|e| {
+                    ParseError::new(line, text, format!("invalid value {:?}: {}", value, e))
+                })
+            })
+            .collect::<Result<Vec<T>, ParseError>>()?;
+        if let Some(first) = rows.first() {
+            if row.len() != first.len() {
+                return Err(ParseError::new(
+                    line,
+                    text,
+                    format!(
+                        "expected {} values like the first row, not {}",
+                        first.len(),
+                        row.len()
+                    ),
+                ));
+            }
+        }
+        rows.push(row);
+    }
+    Ok(Grid::from_rows(rows).expect("grid rows were checked to be the same width"))
+}
+
+/// Rows of single digits with nothing between them, every row as wide as the first.
+pub fn digit_grid(input: &str) -> Result<Grid<u8>, ParseError> {
+    let mut rows = Vec::<Vec<u8>>::new();
+    for (line, text) in numbered_lines(input) {
+        let row = text
+            .chars()
+            .map(|c| {
+                c.to_digit(10).map(|digit| digit as u8).ok_or_else(|| {
+                    ParseError::new(line, text, format!("expected only digits, not {:?}", c))
+                })
+            })
+            .collect::<Result<Vec<u8>, ParseError>>()?;
+        if let Some(first) = rows.first() {
+            if row.len() != first.len() {
+                return Err(ParseError::new(
+                    line,
+                    text,
+                    format!(
+                        "expected {} digits like the first row, not {}",
+                        first.len(),
+                        row.len()
+                    ),
+                ));
+            }
+        }
+        rows.push(row);
+    }
+    Ok(Grid::from_rows(rows).expect("grid rows were checked to be the same width"))
+}
+
+/// Lines of `0`s and `1`s, every line as wide as the first.
+pub fn bit_lines(input: &str) -> Result<Vec<&str>, ParseError> {
+    let mut lines = Vec::<&str>::new();
+    for (line, text) in numbered_lines(input) {
+        if let Some(bad) = text.chars().find(|&c| c != '0' && c != '1') {
+            return Err(ParseError::new(
+                line,
+                text,
+                format!("expected only 0s and 1s, not {:?}", bad),
+            ));
+        }
+        if let Some(first) = lines.first() {
+            if text.len() != first.len() {
+                return Err(ParseError::new(
+                    line,
+                    text,
+                    format!(
+                        "expected {} bits like the first line, not {}",
+                        first.len(),
+                        text.len()
+                    ),
+                ));
+            }
+        }
+        lines.push(text);
+    }
+    Ok(lines)
+}
+
+#[cfg(test)]
+mod test {
+    use crate::parse::{
+        bit_lines, blocks, comma_separated, digit_grid, lines_of, whitespace_grid, Block,
+    };
+
+    #[test]
+    fn test_lines_of() {
+        assert_eq!(lines_of::<i32>(" 1\n\n-2 \n3\n"), Ok(vec![1, -2, 3]));
+        let error = lines_of::<i32>("1\n2\nthree").unwrap_err();
+        assert_eq!((error.line, error.text.as_str()), (3, "three"));
+    }
+
+    #[test]
+    fn test_blocks() {
+        let input = "\n1,2\r\n\r\n\r\na b\n c d\n\n\ne\n";
+        assert_eq!(
+            blocks(input),
+            vec![
+                Block {
+                    first_line: 2,
+                    lines: vec!["1,2"]
+                },
+                Block {
+                    first_line: 5,
+                    lines: vec!["a b", " c d"]
+                },
+                Block {
+                    first_line: 9,
+                    lines: vec!["e"]
+                },
+            ]
+        );
+        assert_eq!(blocks(input)[1].last_line(), 6);
+    }
+
+    #[test]
+    fn test_comma_separated() {
+        assert_eq!(comma_separated::<u8>("\n3, 4 ,5\n"), Ok(vec![3, 4, 5]));
+        assert_eq!(comma_separated::<u8>("\n3,4,x").unwrap_err().line, 2);
+        assert_eq!(comma_separated::<u8>("3,4\n5").unwrap_err().line, 2);
+        assert!(comma_separated::<u8>("\n").is_err());
+    }
+
+    #[test]
+    fn test_whitespace_grid() {
+        let grid = whitespace_grid::<u32>("1  2\n 3 4\n").unwrap();
+        assert_eq!(grid.shape(), (2, 2));
+        assert_eq!(grid[(1, 0)], 3);
+        let error = whitespace_grid::<u32>("1 2\n\n3 4 5").unwrap_err();
+        assert_eq!(error.line, 3);
+        assert_eq!(
+            error.to_string(),
+            "line 3: expected 2 values like the first row, not 3, found \"3 4 5\""
+        );
+    }
+
+    #[test]
+    fn test_digit_grid() {
+        let grid = digit_grid("219\n398\n").unwrap();
+        assert_eq!(grid.shape(), (2, 3));
+        assert_eq!(grid[(1, 2)], 8);
+        assert_eq!(digit_grid("12\n1a").unwrap_err().line, 2);
+        assert_eq!(digit_grid("12\n\n123").unwrap_err().line, 3);
+    }
+
+    #[test]
+    fn test_bit_lines() {
+        assert_eq!(bit_lines("010\n111\n"), Ok(vec!["010", "111"]));
+        assert_eq!(bit_lines("010\n121").unwrap_err().line, 2);
+        assert_eq!(bit_lines("010\n11").unwrap_err().line, 2);
+        assert_eq!(bit_lines("010\n11").unwrap_err().offset_lines(10).line, 12);
+    }
+}
@@ -0,0 +1,42 @@
+//! The shape every day takes: parse the input once, then answer both parts from it.
+
+use std::fmt::Debug;
+
+use anyhow::Result;
+
+pub trait Solution {
+    /// Shown before anything else, e.g. "Day 4: Giant Squid".
+    const TITLE: &'static str;
+    const PART_ONE_QUESTION: &'static str;
+    const PART_TWO_QUESTION: &'static str;
+
+    type Input;
+    type PartOne: Debug;
+    type PartTwo: Debug;
+
+    fn parse(raw_input: &str) -> Result<Self::Input>;
+    /// Errors for inputs that parse but have no answer, e.g. a bingo game nobody wins.
+    fn part_one(input: &Self::Input) -> Result<Self::PartOne>;
+    fn part_two(input: &Self::Input) -> Result<Self::PartTwo>;
+}
+
+/// Reads `input` from the working directory and prints both questions with their answers.
+pub fn run<S: Solution>() -> Result<()> {
+    println!("{}\n", S::TITLE);
+    println!("Reading input...");
+    let raw_input = crate::read_input()?;
+    println!("Parsing input...");
+    let input = S::parse(&raw_input)?;
+
+    println!("Part One:\n");
+    let answer = S::part_one(&input)?;
+    println!("Q: {}", S::PART_ONE_QUESTION);
+    println!("A: {:?}", answer);
+
+    println!("\n\nPart Two:\n");
+    let answer = S::part_two(&input)?;
+    println!("Q: {}", S::PART_TWO_QUESTION);
+    println!("A: {:?}", answer);
+
+    Ok(())
+}
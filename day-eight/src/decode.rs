@@ -0,0 +1,223 @@
+//! Working out which scrambled pattern shows which digit.
+//!
+//! [`deduce`] reasons from the pattern sizes and which patterns contain which, the way you'd do it
+//! by hand. [`brute_force`] tries every one of the 5040 ways the seven wires could be crossed and
+//! keeps the one that turns all ten patterns into real digits, slow but hard to get wrong, so the
+//! two can check each other.
+
+use std::fmt;
+
+use anyhow::{bail, Result};
+use clap::ValueEnum;
+
+use crate::{Entry, Pattern, SEGMENTS};
+
+/// The segments lit for every digit on a correctly wired display, `a` to `g` from bit 0 up.
+pub const DIGITS: [Pattern; 10] = [
+    Pattern(0b1110111), //0 abcefg
+    Pattern(0b0100100), //1 cf
+    Pattern(0b1011101), //2 acdeg
+    Pattern(0b1101101), //3 acdfg
+    Pattern(0b0101110), //4 bcdf
+    Pattern(0b1101011), //5 abdfg
+    Pattern(0b1111011), //6 abdefg
+    Pattern(0b0100101), //7 acf
+    Pattern(0b1111111), //8 abcdefg
+    Pattern(0b1101111), //9 abcdfg
+];
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy, ValueEnum)]
+pub enum Decoder {
+    /// Deduce the digits from pattern sizes and overlaps
+    Deduce,
+    /// Try every wiring
+    BruteForce,
+}
+
+impl Decoder {
+    pub fn decode(self, entry: &Entry) -> Result<Decoding> {
+        match self {
+            Decoder::Deduce => deduce(entry),
+            Decoder::BruteForce => brute_force(entry),
+        }
+    }
+}
+
+/// The scrambled pattern showing every digit on one display.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub struct Decoding {
+    pub digits: [Pattern; 10],
+}
+
+impl Decoding {
+    pub fn digit(&self, pattern: Pattern) -> Option<u8> {
+        self.digits
+            .iter()
+            .position(|&digit| digit == pattern)
+            .map(|digit| digit as u8)
+    }
+
+    /// The four output digits read as one number.
+    pub fn output_value(&self, entry: &Entry) -> Result<u32> {
+        entry
+            .outputs
+            .iter()
+            .try_fold(0, |value, &output| match self.digit(output) {
+                Some(digit) => Ok(value * 10 + digit as u32),
+                None => bail!("output {} isn't one of the display's patterns", output),
+            })
+    }
+}
+
+/// `0=abcefg 1=cf ...`, in the display's scrambled wires.
+impl fmt::Display for Decoding {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let digits = self
+            .digits
+            .iter()
+            .enumerate()
+            .map(|(digit, pattern)| format!("{}={}", digit, pattern))
+            .collect::<Vec<String>>();
+        write!(f, "{}", digits.join(" "))
+    }
+}
+
+/// 1, 4, 7 and 8 are the only digits with 2, 4, 3 and 7 segments. Of the six segment digits 9
+/// covers all of 4, 0 covers 1 but not 4, and 6 is the other. Of the five segment digits 3 covers
+/// 1, 5 fits inside 6, and 2 is the other.
+pub fn deduce(entry: &Entry) -> Result<Decoding> {
+    let with_len = |len: u32| -> Vec<Pattern> {
+        entry
+            .patterns
+            .iter()
+            .copied()
+            .filter(|pattern| pattern.len() == len)
+            .collect()
+    };
+    let only = |patterns: Vec<Pattern>, digit: u8| -> Result<Pattern> {
+        match patterns.as_slice() {
+            [pattern] => Ok(*pattern),
+            _ => bail!(
+                "expected one pattern for {}, found {}",
+                digit,
+                patterns.len()
+            ),
+        }
+    };
+
+    let one = only(with_len(2), 1)?;
+    let four = only(with_len(4), 4)?;
+    let seven = only(with_len(3), 7)?;
+    let eight = only(with_len(7), 8)?;
+
+    let six_segments = with_len(6);
+    let pick = |patterns: &[Pattern], matches: &dyn Fn(Pattern) -> bool, digit: u8| {
+        only(
+            patterns.iter().copied().filter(|&p| matches(p)).collect(),
+            digit,
+        )
+    };
+    let nine = pick(&six_segments, &|p| p.contains(four), 9)?;
+    let zero = pick(&six_segments, &|p| p.contains(one) && !p.contains(four), 0)?;
+    let six = pick(&six_segments, &|p| !p.contains(one), 6)?;
+
+    let five_segments = with_len(5);
+    let three = pick(&five_segments, &|p| p.contains(one), 3)?;
+    let five = pick(&five_segments, &|p| six.contains(p), 5)?;
+    let two = pick(&five_segments, &|p| !p.contains(one) && !six.contains(p), 2)?;
+
+    Ok(Decoding {
+        digits: [zero, one, two, three, four, five, six, seven, eight, nine],
+    })
+}
+
+/// Tries every wiring, `wiring[scrambled] = real`, until one makes every pattern a real digit.
+pub fn brute_force(entry: &Entry) -> Result<Decoding> {
+    for wiring in wirings() {
+        let unscramble = |pattern: Pattern| {
+            Pattern(
+                (0..SEGMENTS)
+                    .filter(|&wire| pattern.0 & (1 << wire) != 0)
+                    .fold(0, |real, wire| real | 1 << wiring[wire]),
+            )
+        };
+        let mut digits = [None; 10];
+        for &pattern in &entry.patterns {
+            if let Some(digit) = DIGITS.iter().position(|&d| d == unscramble(pattern)) {
+                digits[digit] = Some(pattern);
+            }
+        }
+        if let Some(digits) = digits.iter().copied().collect::<Option<Vec<Pattern>>>() {
+            return Ok(Decoding {
+                digits: digits.try_into().expect("ten digits"),
+            });
+        }
+    }
+    bail!("no wiring turns every pattern into a digit")
+}
+
+/// Every permutation of the seven wires, by Heap's algorithm.
+fn wirings() -> Vec<[usize; SEGMENTS]> {
+    let mut wiring = [0, 1, 2, 3, 4, 5, 6];
+    let mut counters = [0; SEGMENTS];
+    let mut wirings = vec![wiring];
+    let mut i = 1;
+    while i < SEGMENTS {
+        if counters[i] < i {
+            let swap_with = if i % 2 == 0 { 0 } else { counters[i] };
+            wiring.swap(swap_with, i);
+            wirings.push(wiring);
+            counters[i] += 1;
+            i = 1;
+        } else {
+            counters[i] = 0;
+            i += 1;
+        }
+    }
+    wirings
+}
+
+#[cfg(test)]
+mod test {
+    use std::collections::HashSet;
+
+    use crate::decode::{brute_force, deduce, wirings, Decoding};
+    use crate::Entry;
+
+    fn entry() -> Entry {
+        "acedgfb cdfbe gcdfa fbcad dab cefabd cdfgeb eafb cagedb ab | cdfeb fcadb cdfeb cdbaf"
+            .parse()
+            .unwrap()
+    }
+
+    #[test]
+    fn test_wirings_are_every_permutation() {
+        let wirings = wirings();
+        assert_eq!(wirings.len(), 5040);
+        assert_eq!(wirings.iter().collect::<HashSet<_>>().len(), 5040);
+    }
+
+    #[test]
+    fn test_decoders_agree() {
+        let entry = entry();
+        let deduced = deduce(&entry).unwrap();
+        assert_eq!(deduced, brute_force(&entry).unwrap());
+        assert_eq!(deduced.output_value(&entry).unwrap(), 5353);
+        assert_eq!(
+            deduced.to_string(),
+            "0=abcdeg 1=ab 2=acdfg 3=abcdf 4=abef 5=bcdef 6=bcdefg 7=abd 8=abcdefg 9=abcdef"
+        );
+    }
+
+    #[test]
+    fn test_rejects_impossible_patterns() {
+        let entry = "ab abc abcd abcde abcdef abcdefg a b c d | ab ab ab abc"
+            .parse::<Entry>()
+            .unwrap();
+        assert!(deduce(&entry).is_err());
+        assert!(brute_force(&entry).is_err());
+        //abc isn't a digit on the example's display
+        let decoding: Decoding = deduce(&self::entry()).unwrap();
+        assert!(decoding.output_value(&entry).is_err());
+    }
+}
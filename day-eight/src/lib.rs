@@ -0,0 +1,181 @@
+//! Day 8: Seven Segment Search, unscrambling the wiring of four-digit seven-segment displays.
+
+pub mod decode;
+
+use std::fmt;
+use std::str::FromStr;
+
+use anyhow::{bail, Context, Error, Result};
+use common::parse::{lines_of, ParseError};
+use common::solution::Solution;
+
+use decode::Decoder;
+
+pub const SEGMENTS: usize = 7;
+
+/// The lit segments of a digit as bits, `a` is bit 0 up to `g` at bit 6.
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Hash, Default)]
+pub struct Pattern(pub u8);
+
+impl Pattern {
+    /// How many segments are lit.
+    pub fn len(self) -> u32 {
+        self.0.count_ones()
+    }
+
+    pub fn is_empty(self) -> bool {
+        self.0 == 0
+    }
+
+    /// Every segment lit in `other` is lit in this too.
+    pub fn contains(self, other: Pattern) -> bool {
+        self.0 & other.0 == other.0
+    }
+}
+
+impl FromStr for Pattern {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        let mut bits = 0u8;
+        for segment in s.chars() {
+            match segment {
+                'a'..='g' => bits |= 1 << (segment as u8 - b'a'),
+                _ => bail!("segments go from a to g, found {:?}", segment),
+            }
+        }
+        Ok(Pattern(bits))
+    }
+}
+
+/// The lit segments in alphabetical order.
+impl fmt::Display for Pattern {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for segment in 0..SEGMENTS as u8 {
+            if self.0 & (1 << segment) != 0 {
+                write!(f, "{}", (b'a' + segment) as char)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+/// A display's ten unique signal patterns, then the four digits it's showing.
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct Entry {
+    pub patterns: [Pattern; 10],
+    pub outputs: [Pattern; 4],
+}
+
+impl FromStr for Entry {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        let (patterns, outputs) = s
+            .split_once('|')
+            .context("expected the signal patterns and the output separated by |")?;
+        let parse = |patterns: &str| {
+            patterns
+                .split_whitespace()
+                .map(str::parse)
+                .collect::<Result<Vec<Pattern>>>()
+        };
+        let (patterns, outputs) = (parse(patterns)?, parse(outputs)?);
+        Ok(Entry {
+            patterns: patterns.try_into().map_err(|p: Vec<Pattern>| {
+                Error::msg(format!("expected 10 patterns, found {}", p.len()))
+            })?,
+            outputs: outputs.try_into().map_err(|o: Vec<Pattern>| {
+                Error::msg(format!("expected 4 outputs, found {}", o.len()))
+            })?,
+        })
+    }
+}
+
+/// One entry per line.
+pub fn parse_input(raw_input: &str) -> Result<Vec<Entry>, ParseError> {
+    lines_of::<Entry>(raw_input)
+}
+
+/// Output digits that are a 1, 4, 7 or 8, the digits with a segment count all their own.
+pub fn part_one(entries: &[Entry]) -> usize {
+    entries
+        .iter()
+        .flat_map(|entry| entry.outputs.iter())
+        .filter(|output| matches!(output.len(), 2 | 3 | 4 | 7))
+        .count()
+}
+
+/// The sum of every display's output value.
+pub fn part_two(entries: &[Entry], decoder: Decoder) -> Result<u32> {
+    entries
+        .iter()
+        .enumerate()
+        .map(|(index, entry)| {
+            decoder
+                .decode(entry)
+                .and_then(|decoding| decoding.output_value(entry))
+                .with_context(|| format!("failed to decode entry {}", index + 1))
+        })
+        .sum()
+}
+
+pub struct DayEight;
+
+impl Solution for DayEight {
+    const TITLE: &'static str = "Day 8: Seven Segment Search";
+    const PART_ONE_QUESTION: &'static str =
+        "In the output values, how many times do digits 1, 4, 7, or 8 appear?";
+    const PART_TWO_QUESTION: &'static str =
+        "What do you get if you add up all of the output values?";
+
+    type Input = Vec<Entry>;
+    type PartOne = usize;
+    type PartTwo = u32;
+
+    fn parse(raw_input: &str) -> Result<Vec<Entry>> {
+        Ok(parse_input(raw_input)?)
+    }
+
+    fn part_one(entries: &Vec<Entry>) -> Result<usize> {
+        Ok(part_one(entries))
+    }
+
+    fn part_two(entries: &Vec<Entry>) -> Result<u32> {
+        part_two(entries, Decoder::Deduce)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::decode::Decoder;
+    use crate::{parse_input, part_one, part_two, Pattern};
+
+    //the example from the puzzle description
+    const EXAMPLE: &str = include_str!("../example");
+
+    #[test]
+    fn test_example() {
+        let entries = parse_input(EXAMPLE).expect("failed to parse example");
+        assert_eq!(entries.len(), 10);
+        assert_eq!(part_one(&entries), 26);
+        assert_eq!(part_two(&entries, Decoder::Deduce).unwrap(), 61229);
+        assert_eq!(part_two(&entries, Decoder::BruteForce).unwrap(), 61229);
+    }
+
+    #[test]
+    fn test_patterns() {
+        let pattern = "gcb".parse::<Pattern>().unwrap();
+        assert_eq!(pattern, Pattern(0b1000110));
+        assert_eq!(pattern.to_string(), "bcg");
+        assert!(pattern.contains(Pattern(0b0000110)));
+        assert!("abz".parse::<Pattern>().is_err());
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        let error = parse_input("a b c d e f g ab abc abcd | a b c d\na b | c").unwrap_err();
+        assert_eq!(error.line, 2);
+        assert!(parse_input("a b c d e f g ab abc abcd a b c d").is_err());
+    }
+}
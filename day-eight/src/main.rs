@@ -0,0 +1,52 @@
+use anyhow::Result;
+use clap::Parser;
+use common::solution::Solution;
+use day_eight::decode::Decoder;
+use day_eight::{parse_input, part_one, part_two, DayEight};
+
+#[derive(Parser, Debug)]
+#[command(about = DayEight::TITLE)]
+struct Args {
+    /// How to work out which pattern is which digit
+    #[arg(long, value_enum, default_value_t = Decoder::Deduce)]
+    decoder: Decoder,
+
+    /// Print the pattern found for every digit of every display
+    #[arg(long)]
+    show_mappings: bool,
+}
+
+fn main() -> Result<()> {
+    let args = Args::parse();
+
+    println!("Reading input...");
+    let raw_input = common::read_input()?;
+    println!("Parsing input...");
+    let entries = parse_input(&raw_input)?;
+
+    if args.show_mappings {
+        println!("\nDigit patterns of every display:\n");
+        for (index, entry) in entries.iter().enumerate() {
+            let decoding = args.decoder.decode(entry)?;
+            println!(
+                "{:>4}: {} -> {:04}",
+                index + 1,
+                decoding,
+                decoding.output_value(entry)?
+            );
+        }
+        println!();
+    }
+
+    println!("Part One:\n");
+    let answer = part_one(&entries);
+    println!("Q: {}", DayEight::PART_ONE_QUESTION);
+    println!("A: {:?}", answer);
+
+    println!("\n\nPart Two:\n");
+    let answer = part_two(&entries, args.decoder)?;
+    println!("Q: {}", DayEight::PART_TWO_QUESTION);
+    println!("A: {:?}", answer);
+
+    Ok(())
+}
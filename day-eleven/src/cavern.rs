@@ -0,0 +1,157 @@
+//! Stepping the octopuses' energy levels forward, flash by flash.
+
+use common::grid::Grid;
+
+/// An octopus with more energy than this flashes.
+pub const FLASH_THRESHOLD: u8 = 9;
+
+/// The energy of every octopus and how many steps have gone by.
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct Cavern {
+    energy: Grid<u8>,
+    steps_taken: usize,
+}
+
+impl Cavern {
+    pub fn new(energy: Grid<u8>) -> Self {
+        Cavern {
+            energy,
+            steps_taken: 0,
+        }
+    }
+
+    pub fn energy(&self) -> &Grid<u8> {
+        &self.energy
+    }
+
+    pub fn steps_taken(&self) -> usize {
+        self.steps_taken
+    }
+
+    pub fn len(&self) -> usize {
+        let (num_rows, num_columns) = self.energy.shape();
+        num_rows * num_columns
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Every octopus gains 1 energy, then every one past the threshold flashes, giving its 8
+    /// neighbours 1 more, which can set them off too. Each octopus flashes at most once a step and
+    /// ends it at 0. Returns how many flashed.
+    pub fn step(&mut self) -> usize {
+        for energy in self.energy.iter_mut() {
+            *energy += 1;
+        }
+        let mut flashing = self
+            .energy
+            .indexed_iter()
+            .filter(|&(_, &energy)| energy > FLASH_THRESHOLD)
+            .map(|(cell, _)| cell)
+            .collect::<Vec<(usize, usize)>>();
+
+        //only pushed the moment they cross the threshold, so nothing flashes twice
+        while let Some((row, column)) = flashing.pop() {
+            let neighbors = self
+                .energy
+                .neighbors_with_diagonals(row, column)
+                .collect::<Vec<(usize, usize)>>();
+            for neighbor in neighbors {
+                let energy = &mut self.energy[neighbor];
+                *energy += 1;
+                if *energy == FLASH_THRESHOLD + 1 {
+                    flashing.push(neighbor);
+                }
+            }
+        }
+
+        let mut flashes = 0;
+        for energy in self.energy.iter_mut() {
+            if *energy > FLASH_THRESHOLD {
+                *energy = 0;
+                flashes += 1;
+            }
+        }
+        self.steps_taken += 1;
+        flashes
+    }
+
+    /// Steps forever, yielding the cavern after every step.
+    pub fn steps(self) -> Steps {
+        Steps { cavern: self }
+    }
+}
+
+/// The cavern after one step.
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct StepState {
+    /// 1-based, the first step is step 1.
+    pub step: usize,
+    pub flashes: usize,
+    pub energy: Grid<u8>,
+}
+
+impl StepState {
+    /// Every octopus flashed at once, leaving them all at 0.
+    pub fn is_synchronized(&self) -> bool {
+        self.energy.iter().all(|&energy| energy == 0)
+    }
+}
+
+/// An endless iterator of steps, from [`Cavern::steps`].
+#[derive(Debug, Clone)]
+pub struct Steps {
+    cavern: Cavern,
+}
+
+impl Iterator for Steps {
+    type Item = StepState;
+
+    fn next(&mut self) -> Option<StepState> {
+        let flashes = self.cavern.step();
+        Some(StepState {
+            step: self.cavern.steps_taken(),
+            flashes,
+            energy: self.cavern.energy().clone(),
+        })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use common::parse::digit_grid;
+
+    use crate::cavern::Cavern;
+
+    #[test]
+    fn test_small_example() {
+        let mut cavern = Cavern::new(digit_grid("11111\n19991\n19191\n19991\n11111").unwrap());
+        assert_eq!(cavern.step(), 9);
+        assert_eq!(
+            cavern.energy(),
+            &digit_grid("34543\n40004\n50005\n40004\n34543").unwrap()
+        );
+        assert_eq!(cavern.step(), 0);
+        assert_eq!(
+            cavern.energy(),
+            &digit_grid("45654\n51115\n61116\n51115\n45654").unwrap()
+        );
+    }
+
+    #[test]
+    fn test_steps() {
+        let cavern = Cavern::new(digit_grid("99\n99").unwrap());
+        let states = cavern.steps().take(3).collect::<Vec<_>>();
+        assert_eq!(
+            states.iter().map(|state| state.step).collect::<Vec<_>>(),
+            vec![1, 2, 3]
+        );
+        assert_eq!(
+            states.iter().map(|state| state.flashes).collect::<Vec<_>>(),
+            vec![4, 0, 0]
+        );
+        assert!(states[0].is_synchronized());
+        assert_eq!(states[2].energy, digit_grid("22\n22").unwrap());
+    }
+}
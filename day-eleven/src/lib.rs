@@ -0,0 +1,119 @@
+//! Day 11: Dumbo Octopus, simulating the octopuses' flashes until they all flash together.
+
+pub mod cavern;
+
+use std::collections::HashSet;
+
+use anyhow::{bail, Context, Result};
+use common::grid::Grid;
+use common::parse::digit_grid;
+use common::solution::Solution;
+
+pub use cavern::{Cavern, StepState, Steps};
+
+/// The cavern is always 10 octopuses by 10.
+pub const SIZE: usize = 10;
+
+/// A grid of energy levels from 0 to 9, erroring if it isn't 10 by 10.
+pub fn parse_input(raw_input: &str) -> Result<Grid<u8>> {
+    let energy = digit_grid(raw_input)?;
+    if energy.shape() != (SIZE, SIZE) {
+        let (num_rows, num_columns) = energy.shape();
+        bail!(
+            "expected {} rows of {} octopuses, found {} rows of {}",
+            SIZE,
+            SIZE,
+            num_rows,
+            num_columns
+        );
+    }
+    Ok(energy)
+}
+
+/// How many flashes there are in the first `steps` steps.
+pub fn total_flashes(energy: &Grid<u8>, steps: usize) -> usize {
+    Cavern::new(energy.clone())
+        .steps()
+        .take(steps)
+        .map(|state| state.flashes)
+        .sum()
+}
+
+/// The first step where every octopus flashes, if that's ever going to happen.
+///
+/// The cavern only has finitely many states, so once one comes round again without a
+/// synchronized step in between, none ever will.
+pub fn first_synchronized_step(energy: &Grid<u8>) -> Option<usize> {
+    let mut seen = HashSet::new();
+    seen.insert(energy.clone());
+    for state in Cavern::new(energy.clone()).steps() {
+        if state.is_synchronized() {
+            return Some(state.step);
+        }
+        if !seen.insert(state.energy) {
+            return None;
+        }
+    }
+    unreachable!("steps never ends")
+}
+
+pub fn part_one(energy: &Grid<u8>) -> usize {
+    total_flashes(energy, 100)
+}
+
+pub fn part_two(energy: &Grid<u8>) -> Option<usize> {
+    first_synchronized_step(energy)
+}
+
+pub struct DayEleven;
+
+impl Solution for DayEleven {
+    const TITLE: &'static str = "Day 11: Dumbo Octopus";
+    const PART_ONE_QUESTION: &'static str = "How many total flashes are there after 100 steps?";
+    const PART_TWO_QUESTION: &'static str =
+        "What is the first step during which all octopuses flash?";
+
+    type Input = Grid<u8>;
+    type PartOne = usize;
+    type PartTwo = usize;
+
+    fn parse(raw_input: &str) -> Result<Grid<u8>> {
+        parse_input(raw_input)
+    }
+
+    fn part_one(energy: &Grid<u8>) -> Result<usize> {
+        Ok(part_one(energy))
+    }
+
+    fn part_two(energy: &Grid<u8>) -> Result<usize> {
+        part_two(energy).context("the octopuses never synchronize")
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use common::parse::digit_grid;
+
+    use crate::{first_synchronized_step, parse_input, part_one, part_two, total_flashes};
+
+    //the example from the puzzle description
+    const EXAMPLE: &str = include_str!("../example");
+
+    #[test]
+    fn test_example() {
+        let energy = parse_input(EXAMPLE).expect("failed to parse example");
+        assert_eq!(total_flashes(&energy, 10), 204);
+        assert_eq!(part_one(&energy), 1656);
+        assert_eq!(part_two(&energy), Some(195));
+    }
+
+    #[test]
+    fn test_other_sizes() {
+        assert!(parse_input("123\n456").is_err());
+        //the simulation itself isn't tied to 10 by 10
+        assert_eq!(
+            first_synchronized_step(&digit_grid("88\n78").unwrap()),
+            Some(2)
+        );
+    }
+}
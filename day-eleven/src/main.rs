@@ -0,0 +1,30 @@
+use anyhow::Result;
+use clap::Parser;
+use common::solution::{self, Solution};
+use day_eleven::{parse_input, Cavern, DayEleven};
+
+#[derive(Parser, Debug)]
+#[command(about = DayEleven::TITLE)]
+struct Args {
+    /// Print the energy levels after each of the first N steps instead of solving
+    #[arg(long, value_name = "N")]
+    render: Option<usize>,
+}
+
+fn main() -> Result<()> {
+    let args = Args::parse();
+    let Some(steps) = args.render else {
+        return solution::run::<DayEleven>();
+    };
+
+    let raw_input = common::read_input()?;
+    let cavern = Cavern::new(parse_input(&raw_input)?);
+    println!("Before any steps:\n{}", cavern.energy());
+    for state in cavern.steps().take(steps) {
+        println!(
+            "After step {} ({} flashes):\n{}",
+            state.step, state.flashes, state.energy
+        );
+    }
+    Ok(())
+}
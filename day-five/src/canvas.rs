@@ -0,0 +1,153 @@
+//! Where the vent lines get drawn, counting how many lines cross every point.
+//!
+//! The dense canvas is a grid covering every segment's bounding box, fast when the segments are
+//! packed into a small area like the puzzle's 1000x1000. The sparse one only stores the points
+//! some line actually crosses, so a few long lines far apart don't cost a huge grid.
+
+use std::collections::HashMap;
+
+use clap::ValueEnum;
+use common::grid::Grid;
+
+use crate::{Point, Segment};
+
+pub trait Canvas {
+    fn draw_point(&mut self, point: Point);
+
+    /// Points crossed by at least two lines.
+    fn overlaps(&self) -> usize;
+
+    fn draw(&mut self, segment: &Segment) {
+        for point in segment.points() {
+            self.draw_point(point);
+        }
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy, ValueEnum)]
+pub enum Backend {
+    /// A hash map of only the points crossed
+    Sparse,
+    /// A grid of every point in the segments' bounding box, or the sparse canvas if that's too big
+    Dense,
+}
+
+impl Backend {
+    /// A blank canvas big enough for all the segments.
+    pub fn canvas_for(self, segments: &[Segment]) -> Box<dyn Canvas> {
+        match self {
+            Backend::Sparse => Box::new(SparseCanvas::default()),
+            Backend::Dense => match DenseCanvas::covering(segments) {
+                Some(canvas) => Box::new(canvas),
+                None => Box::new(SparseCanvas::default()),
+            },
+        }
+    }
+}
+
+#[derive(Debug, Default)]
+pub struct SparseCanvas {
+    crossings: HashMap<Point, u32>,
+}
+
+impl Canvas for SparseCanvas {
+    fn draw_point(&mut self, point: Point) {
+        *self.crossings.entry(point).or_default() += 1;
+    }
+
+    fn overlaps(&self) -> usize {
+        self.crossings.values().filter(|&&count| count > 1).count()
+    }
+}
+
+#[derive(Debug)]
+pub struct DenseCanvas {
+    /// The bounding box's top left corner, where the grid starts.
+    min_x: usize,
+    min_y: usize,
+    /// Indexed by (y - min_y, x - min_x).
+    crossings: Grid<u32>,
+}
+
+impl DenseCanvas {
+    /// The most cells a dense canvas gets, 64MiB of counts and plenty for the puzzle's 1000x1000.
+    pub const MAX_CELLS: usize = 1 << 24;
+
+    /// A grid over the segments' bounding box, None if it would have more than
+    /// [`DenseCanvas::MAX_CELLS`].
+    pub fn covering(segments: &[Segment]) -> Option<Self> {
+        let points = segments
+            .iter()
+            .flat_map(|segment| [segment.start, segment.end])
+            .collect::<Vec<Point>>();
+        let min_x = points.iter().map(|point| point.x).min().unwrap_or(0);
+        let min_y = points.iter().map(|point| point.y).min().unwrap_or(0);
+        let (width, height) = match points.iter().map(|point| point.x).max() {
+            Some(max_x) => {
+                let max_y = points.iter().map(|point| point.y).max().unwrap_or(0);
+                (
+                    (max_x - min_x).checked_add(1)?,
+                    (max_y - min_y).checked_add(1)?,
+                )
+            }
+            None => (0, 0),
+        };
+        if width.checked_mul(height)? > Self::MAX_CELLS {
+            return None;
+        }
+        Some(DenseCanvas {
+            min_x,
+            min_y,
+            crossings: Grid::filled(height, width, 0),
+        })
+    }
+}
+
+impl Canvas for DenseCanvas {
+    fn draw_point(&mut self, point: Point) {
+        self.crossings[(point.y - self.min_y, point.x - self.min_x)] += 1;
+    }
+
+    fn overlaps(&self) -> usize {
+        self.crossings.iter().filter(|&&count| count > 1).count()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::canvas::{Backend, Canvas, DenseCanvas};
+    use crate::Segment;
+
+    #[test]
+    fn test_dense_covers_only_the_bounding_box() {
+        let segments = ["900,500 -> 903,500", "901,499 -> 901,502"]
+            .iter()
+            .map(|segment| segment.parse::<Segment>().unwrap())
+            .collect::<Vec<Segment>>();
+        let mut canvas = DenseCanvas::covering(&segments).unwrap();
+        assert_eq!(canvas.crossings.shape(), (4, 4));
+        for segment in &segments {
+            canvas.draw(segment);
+        }
+        assert_eq!(canvas.overlaps(), 1);
+    }
+
+    #[test]
+    fn test_dense_too_big_falls_back_to_sparse() {
+        let segments = ["0,0 -> 0,0", "999999,999999 -> 999999,999999", "0,0 -> 0,0"]
+            .iter()
+            .map(|segment| segment.parse::<Segment>().unwrap())
+            .collect::<Vec<Segment>>();
+        assert!(DenseCanvas::covering(&segments).is_none());
+        //a box too wide to even count the cells of
+        let corner = format!("{0},{0} -> {0},{0}", usize::MAX);
+        let huge = [segments[0], corner.parse::<Segment>().unwrap()];
+        assert!(DenseCanvas::covering(&huge).is_none());
+
+        let mut canvas = Backend::Dense.canvas_for(&segments);
+        for segment in &segments {
+            canvas.draw(segment);
+        }
+        assert_eq!(canvas.overlaps(), 1);
+    }
+}
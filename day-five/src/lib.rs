@@ -0,0 +1,205 @@
+//! Day 5: Hydrothermal Venture, finding where lines of hydrothermal vents overlap.
+//!
+//! Every vent line is drawn point by point onto a [`canvas::Canvas`], then the points crossed more
+//! than once are counted.
+
+pub mod canvas;
+
+use std::str::FromStr;
+
+use anyhow::{bail, Context, Error, Result};
+use common::parse::{lines_of, ParseError};
+use common::solution::Solution;
+
+use canvas::Backend;
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Hash)]
+pub struct Point {
+    pub x: usize,
+    pub y: usize,
+}
+
+impl FromStr for Point {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        let (x, y) = s
+            .split_once(',')
+            .with_context(|| format!("expected a point like 3,4, found {:?}", s))?;
+        Ok(Point {
+            x: x.trim().parse()?,
+            y: y.trim().parse()?,
+        })
+    }
+}
+
+/// A line of vents, from one end to the other, including both.
+///
+/// Only horizontal, vertical and 45° diagonal lines are allowed.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub struct Segment {
+    pub start: Point,
+    pub end: Point,
+}
+
+impl Segment {
+    pub fn new(start: Point, end: Point) -> Result<Self> {
+        let segment = Segment { start, end };
+        if !segment.is_axis_aligned() && !segment.is_diagonal() {
+            bail!("segments must be horizontal, vertical or at 45 degrees");
+        }
+        Ok(segment)
+    }
+
+    pub fn is_axis_aligned(&self) -> bool {
+        self.start.x == self.end.x || self.start.y == self.end.y
+    }
+
+    pub fn is_diagonal(&self) -> bool {
+        self.start.x.abs_diff(self.end.x) == self.start.y.abs_diff(self.end.y)
+    }
+
+    /// Every point on the line, from start to end.
+    pub fn points(&self) -> impl Iterator<Item = Point> {
+        let step = |from: usize, to: usize| (to as isize - from as isize).signum();
+        let (step_x, step_y) = (
+            step(self.start.x, self.end.x),
+            step(self.start.y, self.end.y),
+        );
+        let length = self
+            .start
+            .x
+            .abs_diff(self.end.x)
+            .max(self.start.y.abs_diff(self.end.y));
+        let start = self.start;
+        (0..=length as isize).map(move |i| Point {
+            x: start.x.wrapping_add_signed(i * step_x),
+            y: start.y.wrapping_add_signed(i * step_y),
+        })
+    }
+}
+
+impl FromStr for Segment {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        let (start, end) = s
+            .split_once("->")
+            .with_context(|| format!("expected a segment like 0,9 -> 5,9, found {:?}", s))?;
+        Segment::new(start.trim().parse()?, end.trim().parse()?)
+    }
+}
+
+/// One `x1,y1 -> x2,y2` segment per line.
+pub fn parse_input(raw_input: &str) -> Result<Vec<Segment>, ParseError> {
+    lines_of::<Segment>(raw_input)
+}
+
+/// Points where at least two lines overlap, leaving out the diagonal lines unless asked for.
+pub fn count_overlaps(segments: &[Segment], include_diagonals: bool, backend: Backend) -> usize {
+    let segments = segments
+        .iter()
+        .filter(|segment| include_diagonals || segment.is_axis_aligned())
+        .copied()
+        .collect::<Vec<Segment>>();
+    let mut canvas = backend.canvas_for(&segments);
+    for segment in &segments {
+        canvas.draw(segment);
+    }
+    canvas.overlaps()
+}
+
+/// Overlaps of just the horizontal and vertical lines.
+pub fn part_one(segments: &[Segment], backend: Backend) -> usize {
+    count_overlaps(segments, false, backend)
+}
+
+/// Overlaps of every line, diagonals included.
+pub fn part_two(segments: &[Segment], backend: Backend) -> usize {
+    count_overlaps(segments, true, backend)
+}
+
+/// The puzzle as asked, drawn on the dense canvas.
+pub struct DayFive;
+
+impl Solution for DayFive {
+    const TITLE: &'static str = "Day 5: Hydrothermal Venture";
+    const PART_ONE_QUESTION: &'static str =
+        "Consider only horizontal and vertical lines. At how many points do at least two lines overlap?";
+    const PART_TWO_QUESTION: &'static str =
+        "Consider all of the lines. At how many points do at least two lines overlap?";
+
+    type Input = Vec<Segment>;
+    type PartOne = usize;
+    type PartTwo = usize;
+
+    fn parse(raw_input: &str) -> Result<Vec<Segment>> {
+        Ok(parse_input(raw_input)?)
+    }
+
+    fn part_one(segments: &Vec<Segment>) -> Result<usize> {
+        Ok(part_one(segments, Backend::Dense))
+    }
+
+    fn part_two(segments: &Vec<Segment>) -> Result<usize> {
+        Ok(part_two(segments, Backend::Dense))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::canvas::Backend;
+    use crate::{parse_input, part_one, part_two, Point, Segment};
+
+    //the example from the puzzle description
+    const EXAMPLE: &str = include_str!("../example");
+
+    #[test]
+    fn test_example() {
+        let segments = parse_input(EXAMPLE).expect("failed to parse example");
+        assert_eq!(segments.len(), 10);
+        for backend in [Backend::Sparse, Backend::Dense] {
+            assert_eq!(part_one(&segments, backend), 5);
+            assert_eq!(part_two(&segments, backend), 12);
+        }
+    }
+
+    #[test]
+    fn test_points() {
+        let segment = "3,1 -> 1,3".parse::<Segment>().unwrap();
+        assert_eq!(
+            segment.points().collect::<Vec<Point>>(),
+            vec![
+                Point { x: 3, y: 1 },
+                Point { x: 2, y: 2 },
+                Point { x: 1, y: 3 }
+            ]
+        );
+        let single = "4,4 -> 4,4".parse::<Segment>().unwrap();
+        assert_eq!(single.points().count(), 1);
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        let error = parse_input("0,9 -> 5,9\n1,1 -> 2,3").unwrap_err();
+        assert_eq!(error.line, 2);
+        let error = parse_input("0,9 -> 5,9\n\n1,1 => 2,2").unwrap_err();
+        assert_eq!(error.line, 3);
+        assert!(parse_input("0,x -> 5,9").is_err());
+    }
+
+    #[test]
+    fn test_backends_agree_on_far_apart_lines() {
+        let segments =
+            parse_input("0,0 -> 0,5\n0,3 -> 3,0\n5000,5000 -> 5000,5010\n4990,5005 -> 5010,5005")
+                .expect("failed to parse segments");
+        for include_diagonals in [false, true] {
+            assert_eq!(
+                crate::count_overlaps(&segments, include_diagonals, Backend::Sparse),
+                crate::count_overlaps(&segments, include_diagonals, Backend::Dense)
+            );
+        }
+        assert_eq!(part_one(&segments, Backend::Sparse), 1);
+        assert_eq!(part_two(&segments, Backend::Sparse), 2);
+    }
+}
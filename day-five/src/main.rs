@@ -0,0 +1,34 @@
+use anyhow::Result;
+use clap::Parser;
+use common::solution::Solution;
+use day_five::canvas::Backend;
+use day_five::{parse_input, part_one, part_two, DayFive};
+
+#[derive(Parser, Debug)]
+#[command(about = DayFive::TITLE)]
+struct Args {
+    /// How to store the points the lines cross
+    #[arg(long, value_enum, default_value_t = Backend::Dense)]
+    backend: Backend,
+}
+
+fn main() -> Result<()> {
+    let args = Args::parse();
+
+    println!("Reading input...");
+    let raw_input = common::read_input()?;
+    println!("Parsing input...");
+    let segments = parse_input(&raw_input)?;
+
+    println!("Part One:\n");
+    let answer = part_one(&segments, args.backend);
+    println!("Q: {}", DayFive::PART_ONE_QUESTION);
+    println!("A: {:?}", answer);
+
+    println!("\n\nPart Two:\n");
+    let answer = part_two(&segments, args.backend);
+    println!("Q: {}", DayFive::PART_TWO_QUESTION);
+    println!("A: {:?}", answer);
+
+    Ok(())
+}
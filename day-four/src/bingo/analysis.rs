@@ -0,0 +1,175 @@
+//! Which board to pick: when every board wins under the real draws, and how likely each board is to
+//! win first or last when the same numbers are drawn in a random order.
+
+use rand::seq::SliceRandom;
+use rand::SeedableRng;
+use rand_chacha::ChaCha8Rng;
+
+use crate::bingo::game::BingoGame;
+use crate::bingo::rules::WinRule;
+use crate::bingo::{InputNumbersAndBoards, Score};
+
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub struct BoardOutcome {
+    pub board: usize,
+    /// (draw index, score), or None if the board never wins.
+    pub win: Option<(usize, Score)>,
+    /// 1 for the boards that win first, boards that win on the same draw share a rank.
+    pub rank: Option<usize>,
+}
+
+/// How every board does under the draws as given, in board order.
+pub fn analyze(input: InputNumbersAndBoards, rule: &dyn WinRule) -> Vec<BoardOutcome> {
+    let mut outcomes = (0..input.boards.len())
+        .map(|board| BoardOutcome {
+            board,
+            win: None,
+            rank: None,
+        })
+        .collect::<Vec<BoardOutcome>>();
+
+    //wins come out in draw order, so a board's rank is one more than the wins on earlier draws
+    let mut wins_before_this_draw = 0;
+    let mut current_draw = None;
+    for (wins_so_far, win) in BingoGame::new(input, rule).wins().enumerate() {
+        if current_draw != Some(win.draw_index) {
+            current_draw = Some(win.draw_index);
+            wins_before_this_draw = wins_so_far;
+        }
+        outcomes[win.board].win = Some((win.draw_index, win.score));
+        outcomes[win.board].rank = Some(wins_before_this_draw + 1);
+    }
+
+    outcomes
+}
+
+#[derive(Debug, PartialEq, Clone, Copy, Default)]
+pub struct BoardOdds {
+    pub board: usize,
+    /// Trials where this board won on the earliest winning draw.
+    pub first: usize,
+    /// Trials where this board won on the latest winning draw.
+    pub last: usize,
+    /// Trials where this board never won.
+    pub never: usize,
+}
+
+/// Plays `trials` games with the draws shuffled by a [`ChaCha8Rng`] seeded from `seed`, so the
+/// same seed always gives the same odds.
+pub fn monte_carlo(
+    input: &InputNumbersAndBoards,
+    rule: &dyn WinRule,
+    trials: usize,
+    seed: u64,
+) -> Vec<BoardOdds> {
+    let mut rng = ChaCha8Rng::seed_from_u64(seed);
+    let mut odds = (0..input.boards.len())
+        .map(|board| BoardOdds {
+            board,
+            ..Default::default()
+        })
+        .collect::<Vec<BoardOdds>>();
+
+    for _ in 0..trials {
+        let mut shuffled = input.clone();
+        shuffled.numbers.shuffle(&mut rng);
+
+        let outcomes = analyze(shuffled, rule);
+        let draws = outcomes
+            .iter()
+            .filter_map(|outcome| outcome.win.map(|(draw_index, _)| draw_index));
+        let (first_draw, last_draw) = (draws.clone().min(), draws.max());
+        for outcome in outcomes {
+            let board_odds = &mut odds[outcome.board];
+            match outcome.win.map(|(draw_index, _)| draw_index) {
+                None => board_odds.never += 1,
+                draw => {
+                    if draw == first_draw {
+                        board_odds.first += 1;
+                    }
+                    if draw == last_draw {
+                        board_odds.last += 1;
+                    }
+                }
+            }
+        }
+    }
+
+    odds
+}
+
+#[cfg(test)]
+mod test {
+    use crate::bingo::analysis::{analyze, monte_carlo, BoardOutcome};
+    use crate::bingo::rules;
+    use crate::bingo::{BingoBoard, InputNumbersAndBoards};
+
+    fn input() -> InputNumbersAndBoards {
+        InputNumbersAndBoards {
+            numbers: vec![1, 2, 3, 4, 5, 6],
+            boards: vec![
+                BingoBoard::from_rows(vec![vec![5, 6], vec![7, 8]]).unwrap(),
+                BingoBoard::from_rows(vec![vec![1, 2], vec![9, 9]]).unwrap(),
+                BingoBoard::from_rows(vec![vec![3, 4], vec![9, 8]]).unwrap(),
+                BingoBoard::from_rows(vec![vec![4, 3], vec![9, 9]]).unwrap(),
+                BingoBoard::from_rows(vec![vec![7, 8], vec![9, 9]]).unwrap(),
+            ],
+        }
+    }
+
+    #[test]
+    fn test_analyze() {
+        let outcomes = analyze(input(), &rules::standard());
+        assert_eq!(
+            outcomes,
+            vec![
+                BoardOutcome {
+                    board: 0,
+                    win: Some((5, 15 * 6)),
+                    rank: Some(4)
+                },
+                BoardOutcome {
+                    board: 1,
+                    win: Some((1, 18 * 2)),
+                    rank: Some(1)
+                },
+                BoardOutcome {
+                    board: 2,
+                    win: Some((3, 17 * 4)),
+                    rank: Some(2)
+                },
+                BoardOutcome {
+                    board: 3,
+                    win: Some((3, 18 * 4)),
+                    rank: Some(2)
+                },
+                BoardOutcome {
+                    board: 4,
+                    win: None,
+                    rank: None
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn test_monte_carlo_is_seeded() {
+        let rule = rules::standard();
+        let odds = monte_carlo(&input(), &rule, 200, 7);
+        assert_eq!(odds, monte_carlo(&input(), &rule, 200, 7));
+
+        //board 4 can never win, every other board wins in every trial
+        assert_eq!(odds[4].never, 200);
+        assert!(odds[..4].iter().all(|board_odds| board_odds.never == 0));
+        //someone always wins first and last, and ties only add to the counts
+        assert!(
+            odds.iter()
+                .map(|board_odds| board_odds.first)
+                .sum::<usize>()
+                >= 200
+        );
+        assert!(odds.iter().map(|board_odds| board_odds.last).sum::<usize>() >= 200);
+        //every board that can win has a fair shot at some point
+        assert!(odds[..4].iter().all(|board_odds| board_odds.first > 0));
+    }
+}
@@ -0,0 +1,321 @@
+use std::collections::{HashMap, VecDeque};
+
+use crate::bingo::rules::WinRule;
+use crate::bingo::{
+    calculate_winning_board_score, index_numbers, BingoBoard, BingoNumber, InputNumbersAndBoards,
+    Score, SlotLocation,
+};
+
+#[derive(Debug, PartialEq, Clone)]
+pub enum GameEvent {
+    NumberDrawn {
+        draw_index: usize,
+        number: BingoNumber,
+    },
+    CellMarked {
+        board: usize,
+        row: usize,
+        column: usize,
+    },
+    BoardWon {
+        board: usize,
+        draw_index: usize,
+        number: BingoNumber,
+        score: Score,
+    },
+}
+
+/// The details of a [`GameEvent::BoardWon`].
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub struct Win {
+    pub board: usize,
+    pub draw_index: usize,
+    pub number: BingoNumber,
+    pub score: Score,
+}
+
+/// A game of bingo, played one draw at a time as the events are pulled.
+///
+/// Every draw yields a `NumberDrawn`, then a `CellMarked` for each slot it marks, then a `BoardWon`
+/// for each board it wins, in board order. Boards stop being marked once they've won, and the game
+/// ends once every board has. Nothing past the draw of the last event pulled gets simulated, so
+/// stopping at the first win is cheap.
+#[derive(Debug)]
+pub struct BingoGame<'rule> {
+    numbers: Vec<BingoNumber>,
+    boards: Vec<BingoBoard>,
+    rule: &'rule dyn WinRule,
+    slots_by_number: HashMap<BingoNumber, Vec<SlotLocation>>,
+    has_won: Vec<bool>,
+    boards_left: usize,
+    next_draw_index: usize,
+    pending_events: VecDeque<GameEvent>,
+}
+
+impl<'rule> BingoGame<'rule> {
+    pub fn new(input: InputNumbersAndBoards, rule: &'rule dyn WinRule) -> Self {
+        BingoGame {
+            slots_by_number: index_numbers(&input.boards),
+            has_won: vec![false; input.boards.len()],
+            boards_left: input.boards.len(),
+            numbers: input.numbers,
+            boards: input.boards,
+            rule,
+            next_draw_index: 0,
+            pending_events: VecDeque::new(),
+        }
+    }
+
+    /// The boards as marked so far.
+    pub fn boards(&self) -> &[BingoBoard] {
+        &self.boards
+    }
+
+    /// Plays one draw and returns all of its events, or whatever's left of the current draw's if
+    /// some were already pulled. None once every number has been drawn or every board has won.
+    pub fn next_draw(&mut self) -> Option<Vec<GameEvent>> {
+        if self.pending_events.is_empty() && !self.play_next_draw() {
+            return None;
+        }
+        Some(self.pending_events.drain(..).collect())
+    }
+
+    /// Just the wins, in the order they happen.
+    pub fn wins(self) -> impl Iterator<Item = Win> + 'rule {
+        self.filter_map(|event| match event {
+            GameEvent::BoardWon {
+                board,
+                draw_index,
+                number,
+                score,
+            } => Some(Win {
+                board,
+                draw_index,
+                number,
+                score,
+            }),
+            _ => None,
+        })
+    }
+
+    pub fn first_win(self) -> Option<Win> {
+        self.nth_win(0)
+    }
+
+    /// The nth win (counting from zero), playing only as far as needed to find it.
+    pub fn nth_win(self, n: usize) -> Option<Win> {
+        self.wins().nth(n)
+    }
+
+    /// The win of the board that takes longest to win, playing only up to the draw that wins it.
+    pub fn last_win(self) -> Option<Win> {
+        self.wins().last()
+    }
+
+    fn play_next_draw(&mut self) -> bool {
+        if self.boards_left == 0 {
+            return false;
+        }
+        let draw_index = self.next_draw_index;
+        let number = match self.numbers.get(draw_index) {
+            Some(number) => *number,
+            None => return false,
+        };
+        self.next_draw_index += 1;
+        self.pending_events
+            .push_back(GameEvent::NumberDrawn { draw_index, number });
+
+        //only the slots holding this number can change, so mark just those..
+        let mut newly_marked = Vec::new();
+        for &(board, row, column) in self.slots_by_number.get(&number).into_iter().flatten() {
+            if !self.has_won[board] && self.boards[board].mark(row, column) {
+                newly_marked.push((board, row, column));
+                self.pending_events
+                    .push_back(GameEvent::CellMarked { board, row, column });
+            }
+        }
+
+        //..and only their boards can have just won
+        for (board, row, column) in newly_marked {
+            if !self.has_won[board] && self.rule.is_new_win(&self.boards[board], row, column) {
+                self.has_won[board] = true;
+                self.boards_left -= 1;
+                self.pending_events.push_back(GameEvent::BoardWon {
+                    board,
+                    draw_index,
+                    number,
+                    score: calculate_winning_board_score(&self.boards[board], number),
+                });
+            }
+        }
+
+        true
+    }
+}
+
+impl Iterator for BingoGame<'_> {
+    type Item = GameEvent;
+
+    fn next(&mut self) -> Option<GameEvent> {
+        while self.pending_events.is_empty() {
+            if !self.play_next_draw() {
+                return None;
+            }
+        }
+        self.pending_events.pop_front()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::bingo::game::{BingoGame, GameEvent, Win};
+    use crate::bingo::rules;
+    use crate::bingo::{BingoBoard, BingoNumber, InputNumbersAndBoards};
+
+    fn two_by_two_input(numbers: Vec<BingoNumber>) -> InputNumbersAndBoards {
+        InputNumbersAndBoards {
+            numbers,
+            boards: vec![
+                BingoBoard::from_rows(vec![vec![1, 2], vec![3, 4]]).unwrap(),
+                BingoBoard::from_rows(vec![vec![4, 3], vec![5, 6]]).unwrap(),
+            ],
+        }
+    }
+
+    #[test]
+    fn test_events() {
+        let rule = rules::standard();
+        let events = BingoGame::new(two_by_two_input(vec![9, 4, 3]), &rule).collect::<Vec<_>>();
+        assert_eq!(
+            events,
+            vec![
+                GameEvent::NumberDrawn {
+                    draw_index: 0,
+                    number: 9
+                },
+                GameEvent::NumberDrawn {
+                    draw_index: 1,
+                    number: 4
+                },
+                GameEvent::CellMarked {
+                    board: 0,
+                    row: 1,
+                    column: 1
+                },
+                GameEvent::CellMarked {
+                    board: 1,
+                    row: 0,
+                    column: 0
+                },
+                GameEvent::NumberDrawn {
+                    draw_index: 2,
+                    number: 3
+                },
+                GameEvent::CellMarked {
+                    board: 0,
+                    row: 1,
+                    column: 0
+                },
+                GameEvent::CellMarked {
+                    board: 1,
+                    row: 0,
+                    column: 1
+                },
+                GameEvent::BoardWon {
+                    board: 0,
+                    draw_index: 2,
+                    number: 3,
+                    score: 9
+                },
+                GameEvent::BoardWon {
+                    board: 1,
+                    draw_index: 2,
+                    number: 3,
+                    score: 33
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn test_stops_at_the_win_asked_for() {
+        let rule = rules::standard();
+        let numbers = vec![1, 2, 5, 6, 3, 4];
+
+        let mut game = BingoGame::new(two_by_two_input(numbers.clone()), &rule);
+        let first = game
+            .by_ref()
+            .find(|e| matches!(e, GameEvent::BoardWon { .. }));
+        assert!(first.is_some());
+        //the first win was on the second draw, so that's as far as the game got
+        assert_eq!(game.next_draw_index, 2);
+
+        let second = BingoGame::new(two_by_two_input(numbers.clone()), &rule).nth_win(1);
+        let last = BingoGame::new(two_by_two_input(numbers.clone()), &rule).last_win();
+        assert_eq!(
+            second,
+            Some(Win {
+                board: 1,
+                draw_index: 3,
+                number: 6,
+                score: 42
+            })
+        );
+        assert_eq!(second, last);
+        assert_eq!(
+            BingoGame::new(two_by_two_input(numbers), &rule).nth_win(2),
+            None
+        );
+    }
+
+    #[test]
+    fn test_stops_at_the_last_win() {
+        let rule = rules::standard();
+        //every board has won by the fourth draw, so the draws after it are never played
+        let mut game = BingoGame::new(two_by_two_input(vec![1, 2, 5, 6, 3, 4, 9, 9]), &rule);
+        let events = game.by_ref().collect::<Vec<_>>();
+        assert_eq!(game.next_draw_index, 4);
+        assert!(matches!(
+            events.last(),
+            Some(GameEvent::BoardWon { board: 1, .. })
+        ));
+        assert_eq!(game.next_draw(), None);
+    }
+
+    #[test]
+    fn test_next_draw() {
+        let rule = rules::standard();
+        let mut game = BingoGame::new(two_by_two_input(vec![9, 4, 3]), &rule);
+        assert_eq!(
+            game.next_draw(),
+            Some(vec![GameEvent::NumberDrawn {
+                draw_index: 0,
+                number: 9
+            }])
+        );
+        assert_eq!(game.next_draw().map(|events| events.len()), Some(3));
+        assert!(game.boards()[0].is_marked(1, 1) && !game.boards()[0].is_marked(1, 0));
+        assert_eq!(game.next_draw().map(|events| events.len()), Some(5));
+        assert_eq!(game.next_draw(), None);
+    }
+
+    #[test]
+    fn test_repeated_draw_marks_nothing_new() {
+        let rule = rules::standard();
+        let events = BingoGame::new(two_by_two_input(vec![1, 1, 2]), &rule).collect::<Vec<_>>();
+        let marked = events
+            .iter()
+            .filter(|e| matches!(e, GameEvent::CellMarked { board: 0, .. }))
+            .count();
+        assert_eq!(marked, 2);
+        assert_eq!(
+            events.last(),
+            Some(&GameEvent::BoardWon {
+                board: 0,
+                draw_index: 2,
+                number: 2,
+                score: 14
+            })
+        );
+    }
+}
@@ -0,0 +1,297 @@
+//! Random puzzle inputs, with their answers worked out by a separate, deliberately simple solver
+//! so they can be used to check the real one.
+
+use std::collections::HashMap;
+
+use anyhow::{bail, Result};
+use rand::seq::index;
+use rand::SeedableRng;
+use rand_chacha::ChaCha8Rng;
+
+use crate::bingo::{BingoNumber, Score};
+
+#[derive(Debug, PartialEq, Clone, clap::Args)]
+pub struct GenerateOptions {
+    /// Seed for the generator, the same options and seed always give the same puzzle
+    #[arg(long, default_value_t = 2021)]
+    pub seed: u64,
+
+    /// How many boards to generate
+    #[arg(long, default_value_t = 100)]
+    pub boards: usize,
+
+    /// Rows on every board
+    #[arg(long, default_value_t = 5)]
+    pub rows: usize,
+
+    /// Columns on every board
+    #[arg(long, default_value_t = 5)]
+    pub columns: usize,
+
+    /// Numbers are picked from 0 up to and including this
+    #[arg(long, default_value_t = 99)]
+    pub max_number: BingoNumber,
+
+    /// How many numbers get drawn, every number is drawn at most once
+    #[arg(long, default_value_t = 100)]
+    pub draws: usize,
+}
+
+#[derive(Debug, PartialEq, Clone)]
+pub struct GeneratedPuzzle {
+    /// The input, in the same format as the puzzle's.
+    pub text: String,
+    /// The score of the first board to win, None if no board wins.
+    pub part_one: Option<Score>,
+    /// The score of the last board to win, None if no board wins.
+    pub part_two: Option<Score>,
+}
+
+/// Generates boards of distinct numbers and a draw sequence without repeats, like the real inputs.
+pub fn generate(options: &GenerateOptions) -> Result<GeneratedPuzzle> {
+    let available_numbers = options.max_number as usize + 1;
+    if options.rows == 0 || options.columns == 0 {
+        bail!("boards need at least one row and one column");
+    }
+    let cells = match options.rows.checked_mul(options.columns) {
+        Some(cells) => cells,
+        None => bail!("a {}x{} board is too big", options.rows, options.columns),
+    };
+    if cells > available_numbers {
+        bail!(
+            "a {}x{} board needs {} different numbers, but only 0 to {} are available",
+            options.rows,
+            options.columns,
+            cells,
+            options.max_number
+        );
+    }
+    if options.draws > available_numbers {
+        bail!(
+            "can't draw {} different numbers from 0 to {}",
+            options.draws,
+            options.max_number
+        );
+    }
+
+    let mut rng = ChaCha8Rng::seed_from_u64(options.seed);
+    //picks distinct indices straight from 0..=max, without building the whole range
+    let mut pick = |count: usize| {
+        index::sample(&mut rng, available_numbers, count)
+            .into_iter()
+            .map(|number| number as BingoNumber)
+            .collect::<Vec<BingoNumber>>()
+    };
+    let draws = pick(options.draws);
+    let boards = (0..options.boards)
+        .map(|_| {
+            let picked = pick(cells);
+            picked
+                .chunks(options.columns)
+                .map(|row| row.to_vec())
+                .collect::<Vec<Vec<BingoNumber>>>()
+        })
+        .collect::<Vec<Vec<Vec<BingoNumber>>>>();
+
+    let (part_one, part_two) = reference_answers(&draws, &boards);
+    Ok(GeneratedPuzzle {
+        text: format_puzzle(&draws, &boards, options.max_number),
+        part_one,
+        part_two,
+    })
+}
+
+fn format_puzzle(
+    draws: &[BingoNumber],
+    boards: &[Vec<Vec<BingoNumber>>],
+    max: BingoNumber,
+) -> String {
+    let width = max.to_string().len();
+    let mut text = draws
+        .iter()
+        .map(|number| number.to_string())
+        .collect::<Vec<String>>()
+        .join(",");
+    text.push('\n');
+    for board in boards {
+        text.push('\n');
+        for row in board {
+            let row = row
+                .iter()
+                .map(|number| format!("{:>width$}", number, width = width))
+                .collect::<Vec<String>>()
+                .join(" ");
+            text.push_str(&row);
+            text.push('\n');
+        }
+    }
+    text
+}
+
+/// Solves the puzzle without playing it: a row or column wins on the draw of its latest drawn
+/// number, and a board wins on the earliest of those.
+///
+/// Boards winning on the same draw are ordered by index, so the first is the lowest board and the
+/// last is the highest, same as the puzzle's reading order.
+fn reference_answers(
+    draws: &[BingoNumber],
+    boards: &[Vec<Vec<BingoNumber>>],
+) -> (Option<Score>, Option<Score>) {
+    let draw_index_of = draws
+        .iter()
+        .enumerate()
+        .map(|(draw_index, number)| (*number, draw_index))
+        .collect::<HashMap<BingoNumber, usize>>();
+    let line_wins_at = |line: Vec<BingoNumber>| {
+        line.iter()
+            .map(|number| draw_index_of.get(number).copied())
+            .collect::<Option<Vec<usize>>>()
+            .and_then(|draw_indexes| draw_indexes.into_iter().max())
+    };
+
+    let mut wins = Vec::new();
+    for board in boards {
+        let rows = board.iter().cloned();
+        let columns =
+            (0..board[0].len()).map(|column| board.iter().map(|row| row[column]).collect());
+        let won_at = rows.chain(columns).filter_map(line_wins_at).min();
+
+        if let Some(won_at) = won_at {
+            let unmarked = board
+                .iter()
+                .flatten()
+                .filter(|number| draw_index_of.get(number).is_none_or(|&d| d > won_at))
+                .map(|&number| number as Score)
+                .sum::<Score>();
+            wins.push((won_at, unmarked * draws[won_at] as Score));
+        }
+    }
+
+    //min_by_key keeps the first of equal keys and max_by_key the last
+    let first = wins.iter().min_by_key(|(won_at, _)| *won_at);
+    let last = wins.iter().max_by_key(|(won_at, _)| *won_at);
+    (
+        first.map(|(_, score)| *score),
+        last.map(|(_, score)| *score),
+    )
+}
+
+#[cfg(test)]
+mod test {
+    use crate::bingo::game::BingoGame;
+    use crate::bingo::generate::{generate, reference_answers, GenerateOptions};
+    use crate::bingo::parse::parse_input;
+    use crate::bingo::rules;
+
+    fn options(seed: u64) -> GenerateOptions {
+        GenerateOptions {
+            seed,
+            boards: 100,
+            rows: 5,
+            columns: 5,
+            max_number: 99,
+            draws: 100,
+        }
+    }
+
+    #[test]
+    fn test_reference_answers_on_the_example() {
+        let draws = vec![
+            7, 4, 9, 5, 11, 17, 23, 2, 0, 14, 21, 24, 10, 16, 13, 6, 15, 25, 12, 22, 18, 20, 8, 19,
+            3, 26, 1,
+        ];
+        let boards = vec![
+            vec![
+                vec![22, 13, 17, 11, 0],
+                vec![8, 2, 23, 4, 24],
+                vec![21, 9, 14, 16, 7],
+                vec![6, 10, 3, 18, 5],
+                vec![1, 12, 20, 15, 19],
+            ],
+            vec![
+                vec![3, 15, 0, 2, 22],
+                vec![9, 18, 13, 17, 5],
+                vec![19, 8, 7, 25, 23],
+                vec![20, 11, 10, 24, 4],
+                vec![14, 21, 16, 12, 6],
+            ],
+            vec![
+                vec![14, 21, 17, 24, 4],
+                vec![10, 16, 15, 9, 19],
+                vec![18, 8, 23, 26, 20],
+                vec![22, 11, 13, 6, 5],
+                vec![2, 0, 12, 3, 7],
+            ],
+        ];
+        assert_eq!(reference_answers(&draws, &boards), (Some(4512), Some(1924)));
+    }
+
+    #[test]
+    fn test_generated_puzzles_match_the_game() {
+        let shapes = [
+            (5, 5, 99, 100),
+            (3, 3, 20, 15),
+            (7, 7, 1000, 600),
+            (2, 6, 30, 30),
+        ];
+        for seed in 0..20 {
+            for &(rows, columns, max_number, draws) in &shapes {
+                let options = GenerateOptions {
+                    rows,
+                    columns,
+                    max_number,
+                    draws,
+                    ..options(seed)
+                };
+                let puzzle = generate(&options).expect("failed to generate puzzle");
+                let input = parse_input(&puzzle.text).expect("failed to parse generated puzzle");
+                assert_eq!(input.boards.len(), options.boards);
+                assert_eq!(input.numbers.len(), options.draws);
+
+                let rule = rules::standard();
+                let first = BingoGame::new(input.clone(), &rule).first_win();
+                let last = BingoGame::new(input, &rule).last_win();
+                assert_eq!(first.map(|win| win.score), puzzle.part_one, "{:?}", options);
+                assert_eq!(last.map(|win| win.score), puzzle.part_two, "{:?}", options);
+            }
+        }
+    }
+
+    #[test]
+    fn test_generate_is_seeded_and_checks_options() {
+        assert_eq!(
+            generate(&options(1)).unwrap(),
+            generate(&options(1)).unwrap()
+        );
+        assert_ne!(
+            generate(&options(1)).unwrap(),
+            generate(&options(2)).unwrap()
+        );
+
+        let too_many_cells = GenerateOptions {
+            max_number: 10,
+            draws: 5,
+            ..options(1)
+        };
+        assert!(generate(&too_many_cells).is_err());
+        let too_many_draws = GenerateOptions {
+            draws: 101,
+            ..options(1)
+        };
+        assert!(generate(&too_many_draws).is_err());
+        let overflowing = GenerateOptions {
+            rows: usize::MAX,
+            columns: 2,
+            ..options(1)
+        };
+        assert!(generate(&overflowing).is_err());
+
+        //the range is sampled from, never built
+        let huge_range = GenerateOptions {
+            max_number: 4_000_000_000,
+            ..options(1)
+        };
+        let puzzle = generate(&huge_range).unwrap();
+        assert!(parse_input(&puzzle.text).is_ok());
+    }
+}
@@ -0,0 +1,158 @@
+pub mod analysis;
+pub mod game;
+pub mod generate;
+pub mod parse;
+pub mod render;
+pub mod rules;
+pub mod validate;
+
+use std::collections::HashMap;
+
+use anyhow::{bail, Result};
+use common::grid::Grid;
+
+/// Numbers on the boards and in the draws, halls can number well past 255.
+pub type BingoNumber = u32;
+
+/// Big enough for the unmarked sum of any board times any number, so it can't overflow.
+pub type Score = u128;
+
+#[derive(Debug, PartialEq, Clone, Default)]
+pub struct BingoSlot {
+    pub number: BingoNumber,
+    pub marked: bool,
+}
+
+impl BingoSlot {
+    pub fn new_unmarked_with_number(number: BingoNumber) -> Self {
+        BingoSlot {
+            number,
+            marked: false,
+        }
+    }
+}
+
+/// A bingo card of any shape, every board in one game shares the shape of the first one parsed.
+///
+/// Alongside the slots the board keeps how many slots are marked in every row and column, so win
+/// rules don't have to rescan the whole board after every draw.
+#[derive(Debug, PartialEq, Clone)]
+pub struct BingoBoard {
+    slots: Grid<BingoSlot>,
+    row_marks: Vec<usize>,
+    column_marks: Vec<usize>,
+    marked_count: usize,
+}
+
+impl BingoBoard {
+    pub fn new(slots: Grid<BingoSlot>) -> Self {
+        let (num_rows, num_columns) = slots.shape();
+        let mut board = BingoBoard {
+            slots: slots.map(|slot| BingoSlot::new_unmarked_with_number(slot.number)),
+            row_marks: vec![0; num_rows],
+            column_marks: vec![0; num_columns],
+            marked_count: 0,
+        };
+        for ((row, column), slot) in slots.indexed_iter() {
+            if slot.marked {
+                board.mark(row, column);
+            }
+        }
+        board
+    }
+
+    pub fn from_rows(rows: Vec<Vec<BingoNumber>>) -> Result<Self> {
+        let num_rows = rows.len();
+        let num_columns = rows.first().map_or(0, |row| row.len());
+        if let Some(row) = rows.iter().find(|row| row.len() != num_columns) {
+            bail!(
+                "board rows must all be the same length, found a row of {} numbers in a board {} wide",
+                row.len(),
+                num_columns
+            );
+        }
+
+        let slots = rows
+            .into_iter()
+            .flatten()
+            .map(BingoSlot::new_unmarked_with_number)
+            .collect();
+        Ok(BingoBoard::new(Grid::from_vec(
+            num_rows,
+            num_columns,
+            slots,
+        )?))
+    }
+
+    /// (rows, columns)
+    pub fn shape(&self) -> (usize, usize) {
+        self.slots.shape()
+    }
+
+    pub fn slots(&self) -> &Grid<BingoSlot> {
+        &self.slots
+    }
+
+    pub fn is_marked(&self, row: usize, column: usize) -> bool {
+        self.slots[(row, column)].marked
+    }
+
+    /// Marks the slot, returning false if it was already marked.
+    pub fn mark(&mut self, row: usize, column: usize) -> bool {
+        let slot = &mut self.slots[(row, column)];
+        if slot.marked {
+            return false;
+        }
+        slot.marked = true;
+        self.row_marks[row] += 1;
+        self.column_marks[column] += 1;
+        self.marked_count += 1;
+        true
+    }
+
+    pub fn marks_in_row(&self, row: usize) -> usize {
+        self.row_marks[row]
+    }
+
+    pub fn marks_in_column(&self, column: usize) -> usize {
+        self.column_marks[column]
+    }
+
+    pub fn marked_count(&self) -> usize {
+        self.marked_count
+    }
+}
+
+/// Where a number sits: (board index, row, column).
+pub type SlotLocation = (usize, usize, usize);
+
+/// Every slot holding each number, across all the boards, so a draw only touches the slots it marks.
+pub fn index_numbers(boards: &[BingoBoard]) -> HashMap<BingoNumber, Vec<SlotLocation>> {
+    let mut index = HashMap::<BingoNumber, Vec<SlotLocation>>::new();
+    for (board_index, board) in boards.iter().enumerate() {
+        for ((row, column), slot) in board.slots.indexed_iter() {
+            index
+                .entry(slot.number)
+                .or_default()
+                .push((board_index, row, column));
+        }
+    }
+    index
+}
+
+#[derive(Debug, PartialEq, Clone)]
+pub struct InputNumbersAndBoards {
+    pub numbers: Vec<BingoNumber>,
+    pub boards: Vec<BingoBoard>,
+}
+
+pub fn calculate_winning_board_score(board: &BingoBoard, winning_number: BingoNumber) -> Score {
+    let summed = board.slots().iter().fold(0 as Score, |sum, slot| {
+        if !slot.marked {
+            sum + slot.number as Score
+        } else {
+            sum
+        }
+    });
+    summed * winning_number as Score
+}
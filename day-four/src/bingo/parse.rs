@@ -0,0 +1,257 @@
+//! The puzzle input: a line of comma separated draws, then boards of whitespace separated numbers
+//! with blank lines between them.
+//!
+//! Any number of blank lines are allowed before, between and after the sections, lines may end in
+//! CRLF, and every line may be indented. The first board decides the shape all the others must have.
+
+use std::fmt;
+
+use nom::character::complete::{char, space0, space1, u32 as number};
+use nom::combinator::all_consuming;
+use nom::multi::separated_list1;
+use nom::sequence::delimited;
+use nom::IResult;
+
+use common::parse::blocks;
+
+use crate::bingo::{BingoBoard, BingoNumber, InputNumbersAndBoards};
+
+/// What went wrong and on which (1-based) line.
+#[derive(Debug, PartialEq, Clone)]
+pub enum ParseError {
+    MissingDraws,
+    InvalidDraws {
+        line: usize,
+        text: String,
+    },
+    InvalidBoardRow {
+        line: usize,
+        text: String,
+    },
+    RowWidth {
+        line: usize,
+        columns: usize,
+        expected_columns: usize,
+    },
+    PartialBoard {
+        first_line: usize,
+        last_line: usize,
+        rows: usize,
+        expected_rows: usize,
+    },
+    OversizeBoard {
+        first_line: usize,
+        last_line: usize,
+        rows: usize,
+        expected_rows: usize,
+    },
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ParseError::MissingDraws => write!(f, "the input is empty, expected a line of draws"),
+            ParseError::InvalidDraws { line, text } => write!(
+                f,
+                "line {}: expected comma separated numbers from 0 to {}, found {:?}",
+                line,
+                BingoNumber::MAX,
+                text
+            ),
+            ParseError::InvalidBoardRow { line, text } => write!(
+                f,
+                "line {}: expected whitespace separated numbers from 0 to {}, found {:?}",
+                line,
+                BingoNumber::MAX,
+                text
+            ),
+            ParseError::RowWidth {
+                line,
+                columns,
+                expected_columns,
+            } => write!(
+                f,
+                "line {}: board row has {} numbers, expected {} like the first board",
+                line, columns, expected_columns
+            ),
+            ParseError::PartialBoard {
+                first_line,
+                last_line,
+                rows,
+                expected_rows,
+            } => write!(
+                f,
+                "lines {}-{}: board has only {} rows, expected {} like the first board",
+                first_line, last_line, rows, expected_rows
+            ),
+            ParseError::OversizeBoard {
+                first_line,
+                last_line,
+                rows,
+                expected_rows,
+            } => write!(
+                f,
+                "lines {}-{}: board has {} rows, expected {} like the first board",
+                first_line, last_line, rows, expected_rows
+            ),
+        }
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+fn draws(line: &str) -> IResult<&str, Vec<BingoNumber>> {
+    all_consuming(delimited(
+        space0,
+        separated_list1(delimited(space0, char(','), space0), number),
+        space0,
+    ))(line)
+}
+
+fn board_row(line: &str) -> IResult<&str, Vec<BingoNumber>> {
+    all_consuming(delimited(space0, separated_list1(space1, number), space0))(line)
+}
+
+pub fn parse_input(raw_input: &str) -> Result<InputNumbersAndBoards, ParseError> {
+    let mut blocks = blocks(raw_input).into_iter();
+
+    let draws_block = blocks.next().ok_or(ParseError::MissingDraws)?;
+    let draws_line = draws_block.first_line();
+    let numbers = match draws_block.lines() {
+        [line] => {
+            draws(line)
+                .map_err(|_| ParseError::InvalidDraws {
+                    line: draws_line,
+                    text: line.to_string(),
+                })?
+                .1
+        }
+        //the draws have to be followed by a blank line, so this is most likely a missing one
+        [line, ..] => {
+            return Err(ParseError::InvalidDraws {
+                line: draws_line,
+                text: line.to_string(),
+            })
+        }
+        [] => unreachable!("blocks are never empty"),
+    };
+
+    let mut boards = Vec::new();
+    let mut expected_shape = None;
+    for block in blocks {
+        let (first_line, last_line) = (block.first_line(), block.last_line());
+        let mut rows = Vec::with_capacity(block.lines().len());
+        for (line, text) in block.numbered_lines() {
+            let row = board_row(text)
+                .map_err(|_| ParseError::InvalidBoardRow {
+                    line,
+                    text: text.to_string(),
+                })?
+                .1;
+            let expected_columns = match expected_shape {
+                Some((_, columns)) => columns,
+                None => rows
+                    .first()
+                    .map_or(row.len(), |first: &Vec<BingoNumber>| first.len()),
+            };
+            if row.len() != expected_columns {
+                return Err(ParseError::RowWidth {
+                    line,
+                    columns: row.len(),
+                    expected_columns,
+                });
+            }
+            rows.push(row);
+        }
+
+        let (expected_rows, _) = *expected_shape.get_or_insert((rows.len(), rows[0].len()));
+        if rows.len() < expected_rows {
+            return Err(ParseError::PartialBoard {
+                first_line,
+                last_line,
+                rows: rows.len(),
+                expected_rows,
+            });
+        }
+        if rows.len() > expected_rows {
+            return Err(ParseError::OversizeBoard {
+                first_line,
+                last_line,
+                rows: rows.len(),
+                expected_rows,
+            });
+        }
+        boards.push(
+            BingoBoard::from_rows(rows).expect("board rows were checked to be the same width"),
+        );
+    }
+
+    Ok(InputNumbersAndBoards { numbers, boards })
+}
+
+#[cfg(test)]
+mod test {
+    use crate::bingo::parse::{parse_input, ParseError};
+
+    #[test]
+    fn test_tolerates_blank_lines_and_crlf() {
+        let input = "\r\n\r\n 1, 2 ,3\r\n\r\n\r\n1 2\r\n 3  4 \r\n\r\n\r\n\r\n5 6\r\n7 8\r\n\r\n";
+        let parsed = parse_input(input).expect("failed to parse messy input");
+        assert_eq!(parsed.numbers, vec![1, 2, 3]);
+        assert_eq!(parsed.boards.len(), 2);
+        assert_eq!(parsed.boards[1].slots()[(1, 0)].number, 7);
+    }
+
+    #[test]
+    fn test_reports_bad_boards_with_line_numbers() {
+        assert_eq!(parse_input("\n\n"), Err(ParseError::MissingDraws));
+        assert_eq!(
+            parse_input("1,2,x\n\n1 2\n3 4"),
+            Err(ParseError::InvalidDraws {
+                line: 1,
+                text: "1,2,x".to_string()
+            })
+        );
+        assert_eq!(
+            parse_input("1,2\n1 2\n3 4"),
+            Err(ParseError::InvalidDraws {
+                line: 1,
+                text: "1,2".to_string()
+            })
+        );
+        assert_eq!(
+            parse_input("1,2\n\n1 2\n3 4294967296"),
+            Err(ParseError::InvalidBoardRow {
+                line: 4,
+                text: "3 4294967296".to_string()
+            })
+        );
+        assert_eq!(
+            parse_input("1,2\n\n1 2\n3 4\n\n5 6\n7 8 9"),
+            Err(ParseError::RowWidth {
+                line: 7,
+                columns: 3,
+                expected_columns: 2
+            })
+        );
+        //a trailing partial board is an error, not silently dropped
+        assert_eq!(
+            parse_input("1,2\n\n1 2\n3 4\n\n5 6\n"),
+            Err(ParseError::PartialBoard {
+                first_line: 6,
+                last_line: 6,
+                rows: 1,
+                expected_rows: 2
+            })
+        );
+        assert_eq!(
+            parse_input("1,2\n\n1 2\n3 4\n\n\n5 6\n7 8\n9 10"),
+            Err(ParseError::OversizeBoard {
+                first_line: 7,
+                last_line: 9,
+                rows: 3,
+                expected_rows: 2
+            })
+        );
+    }
+}
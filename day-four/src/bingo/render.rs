@@ -0,0 +1,216 @@
+//! Drawing boards in the terminal: numbers right aligned in their columns, marked slots picked out,
+//! and the slots that won a board picked out again.
+//!
+//! Terminals get ANSI colors, anything else (a pipe, a file) gets brackets so the marks survive.
+
+use std::fmt;
+use std::io::IsTerminal;
+
+use crate::bingo::BingoBoard;
+
+const RESET: &str = "\x1b[0m";
+const MARKED: &str = "\x1b[32m";
+const WINNING: &str = "\x1b[1;7;33m";
+
+/// Clears the terminal and moves the cursor to the top left.
+pub const CLEAR_SCREEN: &str = "\x1b[2J\x1b[H";
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum Style {
+    /// Marked numbers in green, winning ones in bold reversed yellow.
+    Ansi,
+    /// Marked numbers as `[n]`, winning ones as `<n>`.
+    Plain,
+}
+
+impl Style {
+    /// ANSI if stdout is a terminal, plain otherwise.
+    pub fn detect() -> Self {
+        if std::io::stdout().is_terminal() {
+            Style::Ansi
+        } else {
+            Style::Plain
+        }
+    }
+}
+
+/// A board ready to be displayed, with the slots to emphasize as the winning ones.
+#[derive(Debug, Clone, Copy)]
+pub struct BoardView<'a> {
+    pub board: &'a BingoBoard,
+    pub style: Style,
+    pub winning: &'a [(usize, usize)],
+}
+
+impl<'a> BoardView<'a> {
+    pub fn new(board: &'a BingoBoard, style: Style) -> Self {
+        BoardView {
+            board,
+            style,
+            winning: &[],
+        }
+    }
+
+    pub fn with_winning(self, winning: &'a [(usize, usize)]) -> Self {
+        BoardView { winning, ..self }
+    }
+
+    /// One string per row, every row the same visible width.
+    fn lines(&self) -> Vec<String> {
+        let slots = self.board.slots();
+        let width = slots
+            .iter()
+            .map(|slot| slot.number.to_string().len())
+            .max()
+            .unwrap_or(0);
+
+        slots
+            .rows()
+            .enumerate()
+            .map(|(row, slots)| {
+                slots
+                    .iter()
+                    .enumerate()
+                    .map(|(column, slot)| {
+                        let number = format!("{:>width$}", slot.number, width = width);
+                        let winning = self.winning.contains(&(row, column));
+                        match (self.style, winning, slot.marked) {
+                            (Style::Ansi, true, _) => format!(" {}{}{} ", WINNING, number, RESET),
+                            (Style::Ansi, false, true) => {
+                                format!(" {}{}{} ", MARKED, number, RESET)
+                            }
+                            (Style::Plain, true, _) => format!("<{}>", number),
+                            (Style::Plain, false, true) => format!("[{}]", number),
+                            (_, false, false) => format!(" {} ", number),
+                        }
+                    })
+                    .collect::<String>()
+            })
+            .collect()
+    }
+
+    /// The visible width of every line, ignoring the escape codes.
+    fn width(&self) -> usize {
+        let (_, columns) = self.board.shape();
+        let width = self
+            .board
+            .slots()
+            .iter()
+            .map(|slot| slot.number.to_string().len())
+            .max()
+            .unwrap_or(0);
+        columns * (width + 2)
+    }
+}
+
+impl fmt::Display for BoardView<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for line in self.lines() {
+            writeln!(f, "{}", line)?;
+        }
+        Ok(())
+    }
+}
+
+impl fmt::Display for BingoBoard {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        BoardView::new(self, Style::Plain).fmt(f)
+    }
+}
+
+/// Lays boards out side by side, `per_line` to a line, with a title over each.
+pub fn side_by_side(views: &[(String, BoardView)], per_line: usize) -> String {
+    let mut text = String::new();
+    for chunk in views.chunks(per_line.max(1)) {
+        let widths = chunk
+            .iter()
+            .map(|(title, view)| view.width().max(title.chars().count()))
+            .collect::<Vec<usize>>();
+
+        let titles = chunk
+            .iter()
+            .zip(&widths)
+            .map(|((title, _), width)| format!("{:<width$}", title, width = width))
+            .collect::<Vec<String>>();
+        text.push_str(titles.join("   ").trim_end());
+        text.push('\n');
+
+        let lines = chunk
+            .iter()
+            .map(|(_, view)| view.lines())
+            .collect::<Vec<Vec<String>>>();
+        let rows = lines.iter().map(Vec::len).max().unwrap_or(0);
+        for row in 0..rows {
+            let line = lines
+                .iter()
+                .zip(chunk)
+                .zip(&widths)
+                .map(|((lines, (_, view)), width)| {
+                    //pad by visible width, the escape codes would throw off format!'s padding
+                    let padding = " ".repeat(width - view.width());
+                    format!("{}{}", lines.get(row).map_or("", String::as_str), padding)
+                })
+                .collect::<Vec<String>>();
+            text.push_str(line.join("   ").trim_end());
+            text.push('\n');
+        }
+        text.push('\n');
+    }
+    text
+}
+
+#[cfg(test)]
+mod test {
+    use crate::bingo::render::{side_by_side, BoardView, Style};
+    use crate::bingo::BingoBoard;
+
+    fn board() -> BingoBoard {
+        let mut board = BingoBoard::from_rows(vec![vec![1, 22, 3], vec![40, 5, 6]]).unwrap();
+        board.mark(0, 0);
+        board.mark(0, 1);
+        board.mark(0, 2);
+        board.mark(1, 1);
+        board
+    }
+
+    #[test]
+    fn test_plain_display() {
+        let board = board();
+        assert_eq!(board.to_string(), "[ 1][22][ 3]\n 40 [ 5]  6 \n");
+
+        let winning = [(0, 0), (0, 1), (0, 2)];
+        let view = BoardView::new(&board, Style::Plain).with_winning(&winning);
+        assert_eq!(view.to_string(), "< 1><22>< 3>\n 40 [ 5]  6 \n");
+    }
+
+    #[test]
+    fn test_ansi_display() {
+        let board = board();
+        let winning = [(0, 0)];
+        let view = BoardView::new(&board, Style::Ansi).with_winning(&winning);
+        let first_line = view.to_string().lines().next().unwrap().to_string();
+        assert_eq!(
+            first_line,
+            " \x1b[1;7;33m 1\x1b[0m  \x1b[32m22\x1b[0m  \x1b[32m 3\x1b[0m "
+        );
+    }
+
+    #[test]
+    fn test_side_by_side_pads_by_visible_width() {
+        let board = board();
+        let views = [
+            ("Board 0".to_string(), BoardView::new(&board, Style::Ansi)),
+            ("Board 1".to_string(), BoardView::new(&board, Style::Plain)),
+        ];
+        let text = side_by_side(&views, 2);
+        let lines = text.lines().collect::<Vec<&str>>();
+        assert_eq!(lines[0], "Board 0        Board 1");
+        //both boards start in the same place on every line, colored or not
+        let strip = |line: &str| {
+            line.replace("\x1b[32m", "")
+                .replace("\x1b[0m", "")
+                .replace("\x1b[1;7;33m", "")
+        };
+        assert_eq!(strip(lines[2]), " 40   5   6     40 [ 5]  6");
+    }
+}
@@ -0,0 +1,479 @@
+//! Win conditions for a bingo board.
+//!
+//! The puzzle only counts full rows and columns, real halls play plenty of other patterns. Every
+//! pattern is a [`WinRule`], and rules can be combined with [`AnyOf`] and [`AllOf`]. On the command
+//! line rules are written as e.g. `any(rows,columns)`, `all(diagonals,four-corners)` or
+//! `mask:10001/01010/00100/01010/10001`, see [`parse_rule`].
+
+use std::fmt::Debug;
+
+use anyhow::{bail, Context, Result};
+
+use crate::bingo::BingoBoard;
+
+pub trait WinRule: Debug {
+    fn is_win(&self, board: &BingoBoard) -> bool;
+
+    /// The (row, column) of every slot that makes up the win, empty if the board isn't a win.
+    ///
+    /// Rules that can't pick out which slots won give every marked slot of a winning board.
+    fn winning_slots(&self, board: &BingoBoard) -> Vec<(usize, usize)> {
+        if !self.is_win(board) {
+            return Vec::new();
+        }
+        let (rows, columns) = board.shape();
+        (0..rows)
+            .flat_map(|row| (0..columns).map(move |column| (row, column)))
+            .filter(|&(row, column)| board.is_marked(row, column))
+            .collect()
+    }
+
+    /// Same answer as [`WinRule::is_win`] for a board that wasn't a win until (row, column) was
+    /// just marked, which lets rules only look at the lines through that slot.
+    fn is_new_win(&self, board: &BingoBoard, _row: usize, _column: usize) -> bool {
+        self.is_win(board)
+    }
+
+    /// Checks the rule can be won on boards of this (rows, columns) shape.
+    fn validate(&self, _shape: (usize, usize)) -> Result<()> {
+        Ok(())
+    }
+}
+
+/// Any fully marked row.
+#[derive(Debug, Clone, Copy)]
+pub struct Rows;
+
+impl WinRule for Rows {
+    fn is_win(&self, board: &BingoBoard) -> bool {
+        let (rows, columns) = board.shape();
+        (0..rows).any(|row| board.marks_in_row(row) == columns)
+    }
+
+    fn winning_slots(&self, board: &BingoBoard) -> Vec<(usize, usize)> {
+        let (rows, columns) = board.shape();
+        (0..rows)
+            .find(|&row| board.marks_in_row(row) == columns)
+            .map_or_else(Vec::new, |row| {
+                (0..columns).map(|column| (row, column)).collect()
+            })
+    }
+
+    fn is_new_win(&self, board: &BingoBoard, row: usize, _column: usize) -> bool {
+        board.marks_in_row(row) == board.shape().1
+    }
+}
+
+/// Any fully marked column.
+#[derive(Debug, Clone, Copy)]
+pub struct Columns;
+
+impl WinRule for Columns {
+    fn is_win(&self, board: &BingoBoard) -> bool {
+        let (rows, columns) = board.shape();
+        (0..columns).any(|column| board.marks_in_column(column) == rows)
+    }
+
+    fn winning_slots(&self, board: &BingoBoard) -> Vec<(usize, usize)> {
+        let (rows, columns) = board.shape();
+        (0..columns)
+            .find(|&column| board.marks_in_column(column) == rows)
+            .map_or_else(Vec::new, |column| {
+                (0..rows).map(|row| (row, column)).collect()
+            })
+    }
+
+    fn is_new_win(&self, board: &BingoBoard, _row: usize, column: usize) -> bool {
+        board.marks_in_column(column) == board.shape().0
+    }
+}
+
+/// Either corner to corner diagonal fully marked, only square boards have diagonals.
+#[derive(Debug, Clone, Copy)]
+pub struct Diagonals;
+
+impl WinRule for Diagonals {
+    fn is_win(&self, board: &BingoBoard) -> bool {
+        let (rows, columns) = board.shape();
+        if rows != columns || rows == 0 {
+            return false;
+        }
+        let down = (0..rows).all(|i| board.is_marked(i, i));
+        let up = (0..rows).all(|i| board.is_marked(i, columns - 1 - i));
+        down || up
+    }
+
+    fn winning_slots(&self, board: &BingoBoard) -> Vec<(usize, usize)> {
+        let (rows, columns) = board.shape();
+        if rows != columns {
+            return Vec::new();
+        }
+        let down = (0..rows).map(|i| (i, i)).collect::<Vec<(usize, usize)>>();
+        let up = (0..rows)
+            .map(|i| (i, columns - 1 - i))
+            .collect::<Vec<(usize, usize)>>();
+        [down, up]
+            .into_iter()
+            .find(|diagonal| {
+                diagonal
+                    .iter()
+                    .all(|&(row, column)| board.is_marked(row, column))
+            })
+            .unwrap_or_default()
+    }
+
+    fn is_new_win(&self, board: &BingoBoard, row: usize, column: usize) -> bool {
+        let (rows, columns) = board.shape();
+        if rows != columns {
+            return false;
+        }
+        let down = row == column && (0..rows).all(|i| board.is_marked(i, i));
+        let up =
+            row + column == columns - 1 && (0..rows).all(|i| board.is_marked(i, columns - 1 - i));
+        down || up
+    }
+
+    fn validate(&self, (rows, columns): (usize, usize)) -> Result<()> {
+        if rows != columns {
+            bail!("a {}x{} board has no diagonals", rows, columns);
+        }
+        Ok(())
+    }
+}
+
+/// All four corners marked.
+#[derive(Debug, Clone, Copy)]
+pub struct FourCorners;
+
+impl WinRule for FourCorners {
+    fn is_win(&self, board: &BingoBoard) -> bool {
+        let (rows, columns) = board.shape();
+        if rows == 0 || columns == 0 {
+            return false;
+        }
+        corners(board.shape())
+            .iter()
+            .all(|&(row, column)| board.is_marked(row, column))
+    }
+
+    fn winning_slots(&self, board: &BingoBoard) -> Vec<(usize, usize)> {
+        if self.is_win(board) {
+            corners(board.shape()).to_vec()
+        } else {
+            Vec::new()
+        }
+    }
+
+    fn is_new_win(&self, board: &BingoBoard, row: usize, column: usize) -> bool {
+        let (rows, columns) = board.shape();
+        let is_corner = (row == 0 || row == rows - 1) && (column == 0 || column == columns - 1);
+        is_corner && self.is_win(board)
+    }
+}
+
+fn corners((rows, columns): (usize, usize)) -> [(usize, usize); 4] {
+    [
+        (0, 0),
+        (0, columns - 1),
+        (rows - 1, 0),
+        (rows - 1, columns - 1),
+    ]
+}
+
+/// Every slot on the board marked.
+#[derive(Debug, Clone, Copy)]
+pub struct Blackout;
+
+impl WinRule for Blackout {
+    fn is_win(&self, board: &BingoBoard) -> bool {
+        let (rows, columns) = board.shape();
+        board.marked_count() == rows * columns
+    }
+
+    fn winning_slots(&self, board: &BingoBoard) -> Vec<(usize, usize)> {
+        if self.is_win(board) {
+            board.slots().indexed_iter().map(|(slot, _)| slot).collect()
+        } else {
+            Vec::new()
+        }
+    }
+}
+
+/// A user-defined pattern, every (row, column) in the mask has to be marked.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Mask {
+    shape: (usize, usize),
+    cells: Vec<(usize, usize)>,
+}
+
+impl Mask {
+    /// Parses rows of `1` (part of the pattern) and `0` (free) separated by `/`, e.g. `101/010/101`.
+    pub fn parse(pattern: &str) -> Result<Self> {
+        let rows = pattern.split('/').map(str::trim).collect::<Vec<&str>>();
+        let columns = rows.first().map_or(0, |row| row.len());
+        let mut cells = Vec::new();
+        for (row_index, row) in rows.iter().enumerate() {
+            if row.len() != columns {
+                bail!(
+                    "mask row {:?} is {} wide, but the first row is {} wide",
+                    row,
+                    row.len(),
+                    columns
+                );
+            }
+            for (column_index, c) in row.chars().enumerate() {
+                match c {
+                    '1' => cells.push((row_index, column_index)),
+                    '0' => {}
+                    _ => bail!("unexpected character {:?} in mask {:?}", c, pattern),
+                }
+            }
+        }
+        if cells.is_empty() {
+            bail!("mask {:?} doesn't mark any cells", pattern);
+        }
+        Ok(Mask {
+            shape: (rows.len(), columns),
+            cells,
+        })
+    }
+}
+
+impl WinRule for Mask {
+    fn is_win(&self, board: &BingoBoard) -> bool {
+        board.shape() == self.shape
+            && self
+                .cells
+                .iter()
+                .all(|&(row, column)| board.is_marked(row, column))
+    }
+
+    fn winning_slots(&self, board: &BingoBoard) -> Vec<(usize, usize)> {
+        if self.is_win(board) {
+            self.cells.clone()
+        } else {
+            Vec::new()
+        }
+    }
+
+    fn is_new_win(&self, board: &BingoBoard, row: usize, column: usize) -> bool {
+        self.cells.contains(&(row, column)) && self.is_win(board)
+    }
+
+    fn validate(&self, shape: (usize, usize)) -> Result<()> {
+        if shape != self.shape {
+            bail!(
+                "mask is {}x{} but the boards are {}x{}",
+                self.shape.0,
+                self.shape.1,
+                shape.0,
+                shape.1
+            );
+        }
+        Ok(())
+    }
+}
+
+/// Wins when at least one of the rules wins.
+#[derive(Debug)]
+pub struct AnyOf(pub Vec<Box<dyn WinRule>>);
+
+impl WinRule for AnyOf {
+    fn is_win(&self, board: &BingoBoard) -> bool {
+        self.0.iter().any(|rule| rule.is_win(board))
+    }
+
+    fn winning_slots(&self, board: &BingoBoard) -> Vec<(usize, usize)> {
+        self.0
+            .iter()
+            .map(|rule| rule.winning_slots(board))
+            .find(|slots| !slots.is_empty())
+            .unwrap_or_default()
+    }
+
+    fn is_new_win(&self, board: &BingoBoard, row: usize, column: usize) -> bool {
+        //none of the rules were a win before, or this wouldn't have been either
+        self.0
+            .iter()
+            .any(|rule| rule.is_new_win(board, row, column))
+    }
+
+    fn validate(&self, shape: (usize, usize)) -> Result<()> {
+        self.0.iter().try_for_each(|rule| rule.validate(shape))
+    }
+}
+
+/// Wins only when every one of the rules wins at the same time.
+#[derive(Debug)]
+pub struct AllOf(pub Vec<Box<dyn WinRule>>);
+
+impl WinRule for AllOf {
+    fn is_win(&self, board: &BingoBoard) -> bool {
+        !self.0.is_empty() && self.0.iter().all(|rule| rule.is_win(board))
+    }
+
+    fn winning_slots(&self, board: &BingoBoard) -> Vec<(usize, usize)> {
+        if !self.is_win(board) {
+            return Vec::new();
+        }
+        let mut slots = self
+            .0
+            .iter()
+            .flat_map(|rule| rule.winning_slots(board))
+            .collect::<Vec<(usize, usize)>>();
+        slots.sort_unstable();
+        slots.dedup();
+        slots
+    }
+
+    //no is_new_win shortcut, some of the rules may have been a win long before this mark
+
+    fn validate(&self, shape: (usize, usize)) -> Result<()> {
+        self.0.iter().try_for_each(|rule| rule.validate(shape))
+    }
+}
+
+/// The puzzle's rule, any full row or column.
+pub fn standard() -> AnyOf {
+    AnyOf(vec![Box::new(Rows), Box::new(Columns)])
+}
+
+/// Parses a rule like `rows`, `any(rows,columns)` or `all(four-corners,mask:01/10)`.
+pub fn parse_rule(spec: &str) -> Result<Box<dyn WinRule>> {
+    let spec = spec.trim();
+    if let Some(pattern) = spec.strip_prefix("mask:") {
+        return Ok(Box::new(Mask::parse(pattern)?));
+    }
+    if let Some(inner) = spec.strip_prefix("any(").and_then(|s| s.strip_suffix(')')) {
+        return Ok(Box::new(AnyOf(parse_rule_list(inner)?)));
+    }
+    if let Some(inner) = spec.strip_prefix("all(").and_then(|s| s.strip_suffix(')')) {
+        return Ok(Box::new(AllOf(parse_rule_list(inner)?)));
+    }
+    match spec {
+        "rows" => Ok(Box::new(Rows)),
+        "columns" => Ok(Box::new(Columns)),
+        "diagonals" => Ok(Box::new(Diagonals)),
+        "four-corners" => Ok(Box::new(FourCorners)),
+        "blackout" => Ok(Box::new(Blackout)),
+        _ => bail!("unknown win rule {:?}", spec),
+    }
+}
+
+//splits on the commas that aren't nested inside another any(..) or all(..)
+fn parse_rule_list(list: &str) -> Result<Vec<Box<dyn WinRule>>> {
+    let mut rules = Vec::new();
+    let (mut depth, mut start) = (0, 0);
+    for (i, c) in list.char_indices() {
+        match c {
+            '(' => depth += 1,
+            ')' => depth -= 1,
+            ',' if depth == 0 => {
+                rules.push(parse_rule(&list[start..i])?);
+                start = i + 1;
+            }
+            _ => {}
+        }
+    }
+    if depth != 0 {
+        bail!("unbalanced parentheses in win rule {:?}", list);
+    }
+    rules.push(parse_rule(&list[start..]).with_context(|| format!("in rule list {:?}", list))?);
+    Ok(rules)
+}
+
+#[cfg(test)]
+mod test {
+    use crate::bingo::rules::{
+        parse_rule, standard, AllOf, Blackout, Columns, Diagonals, FourCorners, Mask, Rows, WinRule,
+    };
+    use crate::bingo::BingoBoard;
+
+    fn board_marked_at(cells: &[(usize, usize)]) -> BingoBoard {
+        let rows = (0..3)
+            .map(|row| (0..3).map(|column| row * 3 + column).collect())
+            .collect();
+        let mut board = BingoBoard::from_rows(rows).expect("failed to build board");
+        for &(row, column) in cells {
+            board.mark(row, column);
+        }
+        board
+    }
+
+    #[test]
+    fn test_built_in_rules() {
+        let row = board_marked_at(&[(1, 0), (1, 1), (1, 2)]);
+        assert!(Rows.is_win(&row) && !Columns.is_win(&row) && !Diagonals.is_win(&row));
+        assert_eq!(Rows.winning_slots(&row), vec![(1, 0), (1, 1), (1, 2)]);
+        assert!(Columns.winning_slots(&row).is_empty());
+
+        let column = board_marked_at(&[(0, 2), (1, 2), (2, 2)]);
+        assert!(Columns.is_win(&column) && !Rows.is_win(&column));
+
+        let diagonal = board_marked_at(&[(0, 2), (1, 1), (2, 0)]);
+        assert!(Diagonals.is_win(&diagonal) && !standard().is_win(&diagonal));
+        assert_eq!(
+            Diagonals.winning_slots(&diagonal),
+            vec![(0, 2), (1, 1), (2, 0)]
+        );
+
+        let corners = board_marked_at(&[(0, 0), (0, 2), (2, 0), (2, 2)]);
+        assert!(FourCorners.is_win(&corners) && !FourCorners.is_win(&diagonal));
+
+        let all_cells = (0..3)
+            .flat_map(|row| (0..3).map(move |column| (row, column)))
+            .collect::<Vec<(usize, usize)>>();
+        assert!(Blackout.is_win(&board_marked_at(&all_cells)));
+        assert!(!Blackout.is_win(&corners));
+    }
+
+    #[test]
+    fn test_default_winning_slots() {
+        //a rule from outside the crate that only says whether the board won
+        #[derive(Debug)]
+        struct MiddleMarked;
+
+        impl WinRule for MiddleMarked {
+            fn is_win(&self, board: &BingoBoard) -> bool {
+                board.is_marked(1, 1)
+            }
+        }
+
+        let board = board_marked_at(&[(0, 0), (1, 1)]);
+        assert_eq!(MiddleMarked.winning_slots(&board), vec![(0, 0), (1, 1)]);
+        assert!(MiddleMarked
+            .winning_slots(&board_marked_at(&[(0, 0)]))
+            .is_empty());
+    }
+
+    #[test]
+    fn test_mask_and_combinators() {
+        let x = Mask::parse("101/010/101").expect("failed to parse mask");
+        let both_diagonals = board_marked_at(&[(0, 0), (0, 2), (1, 1), (2, 0), (2, 2)]);
+        assert!(x.is_win(&both_diagonals));
+        assert!(!x.is_win(&board_marked_at(&[(0, 0), (1, 1), (2, 2)])));
+
+        let corners_and_diagonal = AllOf(vec![Box::new(FourCorners), Box::new(Diagonals)]);
+        assert!(corners_and_diagonal.is_win(&both_diagonals));
+        assert!(!corners_and_diagonal.is_win(&board_marked_at(&[(0, 0), (1, 1), (2, 2)])));
+    }
+
+    #[test]
+    fn test_parse_rule() {
+        let corners_or_row = board_marked_at(&[(0, 0), (0, 1), (0, 2), (2, 0), (2, 2)]);
+        let rule = parse_rule("all(four-corners, any(rows,mask:000/010/000))")
+            .expect("failed to parse rule");
+        assert!(rule.is_win(&corners_or_row));
+        assert_eq!(
+            rule.winning_slots(&corners_or_row),
+            vec![(0, 0), (0, 1), (0, 2), (2, 0), (2, 2)]
+        );
+        assert!(!parse_rule("diagonals").unwrap().is_win(&corners_or_row));
+
+        assert!(parse_rule("squares").is_err());
+        assert!(parse_rule("any(rows,columns").is_err());
+        assert!(parse_rule("mask:10/1").is_err());
+        assert!(parse_rule("mask:00/00").is_err());
+        assert!(parse_rule("mask:11/11").unwrap().validate((3, 3)).is_err());
+    }
+}
@@ -0,0 +1,198 @@
+//! Checks for repeated numbers, on a single board or in the draw sequence.
+//!
+//! The game itself copes with both, a slot holding a repeated number gets marked along with its
+//! twin, and drawing a number again marks nothing new. Real puzzle inputs never have them though,
+//! so they're usually a sign of a bad input.
+
+use std::collections::HashMap;
+use std::fmt;
+
+use anyhow::{bail, Result};
+use clap::ValueEnum;
+
+use crate::bingo::{BingoNumber, InputNumbersAndBoards};
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy, ValueEnum)]
+pub enum DuplicatePolicy {
+    /// Play on without saying anything
+    Allow,
+    /// Print every duplicate to stderr, then play on
+    Warn,
+    /// Refuse to play the game
+    Reject,
+}
+
+#[derive(Debug, PartialEq, Clone)]
+pub enum Duplicate {
+    OnBoard {
+        board: usize,
+        number: BingoNumber,
+        slots: Vec<(usize, usize)>,
+    },
+    InDraws {
+        number: BingoNumber,
+        draw_indexes: Vec<usize>,
+    },
+}
+
+impl fmt::Display for Duplicate {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Duplicate::OnBoard {
+                board,
+                number,
+                slots,
+            } => write!(
+                f,
+                "board {} has {} in {} slots (row, column): {:?}",
+                board,
+                number,
+                slots.len(),
+                slots
+            ),
+            Duplicate::InDraws {
+                number,
+                draw_indexes,
+            } => write!(
+                f,
+                "{} is drawn {} times, at draws {:?}",
+                number,
+                draw_indexes.len(),
+                draw_indexes
+            ),
+        }
+    }
+}
+
+/// Every repeated number, board duplicates first in board order, then draw duplicates.
+pub fn find_duplicates(input: &InputNumbersAndBoards) -> Vec<Duplicate> {
+    let mut duplicates = Vec::new();
+
+    for (board_index, board) in input.boards.iter().enumerate() {
+        let mut slots_by_number = HashMap::<BingoNumber, Vec<(usize, usize)>>::new();
+        for (slot_location, slot) in board.slots().indexed_iter() {
+            slots_by_number
+                .entry(slot.number)
+                .or_default()
+                .push(slot_location);
+        }
+        let mut repeated = slots_by_number
+            .into_iter()
+            .filter(|(_, slots)| slots.len() > 1)
+            .collect::<Vec<_>>();
+        repeated.sort_by_key(|(_, slots)| slots[0]);
+        duplicates.extend(
+            repeated
+                .into_iter()
+                .map(|(number, slots)| Duplicate::OnBoard {
+                    board: board_index,
+                    number,
+                    slots,
+                }),
+        );
+    }
+
+    let mut draws_by_number = HashMap::<BingoNumber, Vec<usize>>::new();
+    for (draw_index, number) in input.numbers.iter().enumerate() {
+        draws_by_number.entry(*number).or_default().push(draw_index);
+    }
+    let mut repeated = draws_by_number
+        .into_iter()
+        .filter(|(_, draw_indexes)| draw_indexes.len() > 1)
+        .collect::<Vec<_>>();
+    repeated.sort_by_key(|(_, draw_indexes)| draw_indexes[0]);
+    duplicates.extend(
+        repeated
+            .into_iter()
+            .map(|(number, draw_indexes)| Duplicate::InDraws {
+                number,
+                draw_indexes,
+            }),
+    );
+
+    duplicates
+}
+
+/// Applies the policy, erroring with every duplicate found when rejecting them.
+pub fn check_duplicates(input: &InputNumbersAndBoards, policy: DuplicatePolicy) -> Result<()> {
+    if policy == DuplicatePolicy::Allow {
+        return Ok(());
+    }
+
+    let duplicates = find_duplicates(input);
+    if duplicates.is_empty() {
+        return Ok(());
+    }
+
+    let described = duplicates
+        .iter()
+        .map(|duplicate| duplicate.to_string())
+        .collect::<Vec<String>>();
+    match policy {
+        DuplicatePolicy::Reject => bail!(
+            "found {} duplicate numbers:\n{}",
+            duplicates.len(),
+            described.join("\n")
+        ),
+        _ => {
+            for description in described {
+                eprintln!("warning: {}", description);
+            }
+            Ok(())
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::bingo::validate::{check_duplicates, find_duplicates, Duplicate, DuplicatePolicy};
+    use crate::bingo::{BingoBoard, InputNumbersAndBoards};
+
+    #[test]
+    fn test_find_duplicates() {
+        let input = InputNumbersAndBoards {
+            numbers: vec![1, 2, 1, 3, 2, 1],
+            boards: vec![
+                BingoBoard::from_rows(vec![vec![1, 2], vec![3, 4]]).unwrap(),
+                BingoBoard::from_rows(vec![vec![5, 6], vec![6, 5]]).unwrap(),
+            ],
+        };
+        assert_eq!(
+            find_duplicates(&input),
+            vec![
+                Duplicate::OnBoard {
+                    board: 1,
+                    number: 5,
+                    slots: vec![(0, 0), (1, 1)]
+                },
+                Duplicate::OnBoard {
+                    board: 1,
+                    number: 6,
+                    slots: vec![(0, 1), (1, 0)]
+                },
+                Duplicate::InDraws {
+                    number: 1,
+                    draw_indexes: vec![0, 2, 5]
+                },
+                Duplicate::InDraws {
+                    number: 2,
+                    draw_indexes: vec![1, 4]
+                },
+            ]
+        );
+
+        assert!(check_duplicates(&input, DuplicatePolicy::Reject).is_err());
+        assert!(check_duplicates(&input, DuplicatePolicy::Warn).is_ok());
+        assert!(check_duplicates(&input, DuplicatePolicy::Allow).is_ok());
+    }
+
+    #[test]
+    fn test_no_duplicates() {
+        let input = InputNumbersAndBoards {
+            numbers: vec![4, 3, 2, 1],
+            boards: vec![BingoBoard::from_rows(vec![vec![1, 2], vec![3, 4]]).unwrap()],
+        };
+        assert!(find_duplicates(&input).is_empty());
+        assert!(check_duplicates(&input, DuplicatePolicy::Reject).is_ok());
+    }
+}
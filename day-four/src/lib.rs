@@ -0,0 +1,266 @@
+//! Day 4: Giant Squid, playing bingo against a giant squid.
+//!
+//! [`BingoGame`] plays the draws one event at a time under any [`WinRule`], the rest of [`bingo`]
+//! parses, checks, renders, analyzes and generates inputs.
+
+pub mod bingo;
+
+use anyhow::{Context, Result};
+use common::solution::Solution;
+
+pub use bingo::game::{BingoGame, GameEvent, Win};
+pub use bingo::parse::{parse_input, ParseError};
+pub use bingo::rules::WinRule;
+pub use bingo::{BingoBoard, BingoNumber, BingoSlot, InputNumbersAndBoards, Score};
+
+/// The puzzle as asked, with full rows or columns winning.
+pub struct DayFour;
+
+impl Solution for DayFour {
+    const TITLE: &'static str = "Day 4: Giant Squid";
+    const PART_ONE_QUESTION: &'static str =
+        "What will your final score be if you choose the first winning board?";
+    const PART_TWO_QUESTION: &'static str = "Once it wins, what would its final score be?";
+
+    type Input = InputNumbersAndBoards;
+    type PartOne = Score;
+    type PartTwo = Score;
+
+    fn parse(raw_input: &str) -> Result<InputNumbersAndBoards> {
+        Ok(parse_input(raw_input)?)
+    }
+
+    fn part_one(input: &InputNumbersAndBoards) -> Result<Score> {
+        part_one(input.clone(), &bingo::rules::standard()).context("no board wins")
+    }
+
+    fn part_two(input: &InputNumbersAndBoards) -> Result<Score> {
+        part_two(input.clone(), &bingo::rules::standard()).context("no board wins")
+    }
+}
+
+/// The score of the first board to win, None if no board ever wins.
+pub fn part_one(input: InputNumbersAndBoards, rule: &dyn WinRule) -> Option<Score> {
+    BingoGame::new(input, rule).first_win().map(|win| win.score)
+}
+
+/// The score of the last board to win, None if no board ever wins.
+pub fn part_two(input: InputNumbersAndBoards, rule: &dyn WinRule) -> Option<Score> {
+    BingoGame::new(input, rule).last_win().map(|win| win.score)
+}
+
+#[cfg(test)]
+mod test {
+    use crate::bingo::parse::parse_input;
+    use crate::bingo::rules::{self, Diagonals, FourCorners, Rows};
+    use crate::bingo::{BingoBoard, BingoNumber, BingoSlot, InputNumbersAndBoards, Score};
+    use crate::{part_one, part_two};
+    use common::grid::Grid;
+
+    fn test_input() -> InputNumbersAndBoards {
+        let board_1 = Grid::from_rows(vec![
+            vec![22, 13, 17, 11, 0],
+            vec![8, 2, 23, 4, 24],
+            vec![21, 9, 14, 16, 7],
+            vec![6, 10, 3, 18, 5],
+            vec![1, 12, 20, 15, 19],
+        ])
+        .unwrap()
+        .map(|&number| BingoSlot::new_unmarked_with_number(number));
+
+        let board_2 = Grid::from_rows(vec![
+            vec![3, 15, 0, 2, 22],
+            vec![9, 18, 13, 17, 5],
+            vec![19, 8, 7, 25, 23],
+            vec![20, 11, 10, 24, 4],
+            vec![14, 21, 16, 12, 6],
+        ])
+        .unwrap()
+        .map(|&number| BingoSlot::new_unmarked_with_number(number));
+
+        let board_3 = Grid::from_rows(vec![
+            vec![14, 21, 17, 24, 4],
+            vec![10, 16, 15, 9, 19],
+            vec![18, 8, 23, 26, 20],
+            vec![22, 11, 13, 6, 5],
+            vec![2, 0, 12, 3, 7],
+        ])
+        .unwrap()
+        .map(|&number| BingoSlot::new_unmarked_with_number(number));
+
+        InputNumbersAndBoards {
+            numbers: vec![
+                7, 4, 9, 5, 11, 17, 23, 2, 0, 14, 21, 24, 10, 16, 13, 6, 15, 25, 12, 22, 18, 20, 8,
+                19, 3, 26, 1,
+            ],
+            boards: vec![board_1, board_2, board_3]
+                .into_iter()
+                .map(BingoBoard::new)
+                .collect(),
+        }
+    }
+
+    #[test]
+    fn test_parsing() {
+        let example_input = r#"
+        
+        7,4,9,5,11,17,23,2,0,14,21,24,10,16,13,6,15,25,12,22,18,20,8,19,3,26,1
+
+        22 13 17 11  0
+         8  2 23  4 24
+        21  9 14 16  7
+         6 10  3 18  5
+         1 12 20 15 19
+        
+         3 15  0  2 22
+         9 18 13 17  5
+        19  8  7 25 23
+        20 11 10 24  4
+        14 21 16 12  6
+        
+        14 21 17 24  4
+        10 16 15  9 19
+        18  8 23 26 20
+        22 11 13  6  5
+         2  0 12  3  7
+        
+        "#
+        .trim();
+
+        let parsed = parse_input(example_input)
+            .expect("failed to parse input numbers and boards in test example");
+
+        let expected = test_input();
+
+        assert_eq!(expected, parsed)
+    }
+
+    #[test]
+    fn test_part_one() {
+        let input = test_input();
+        let answer = part_one(input, &rules::standard());
+        assert_eq!(answer, Some(4512));
+    }
+
+    #[test]
+    fn test_part_two() {
+        let input = test_input();
+        let answer = part_two(input, &rules::standard());
+        assert_eq!(answer, Some(1924));
+    }
+
+    #[test]
+    fn test_parsing_infers_board_shape() {
+        let example_input = r#"
+        3,1,2
+
+        1 2 3
+        4 5 6
+        7 8 9
+
+        9 8 7
+        6 5 4
+        3 2 1
+        "#
+        .trim();
+
+        let parsed = parse_input(example_input).expect("failed to parse 3x3 boards");
+        assert_eq!(parsed.boards.len(), 2);
+        assert!(parsed.boards.iter().all(|board| board.shape() == (3, 3)));
+
+        //3, 1 and 2 complete the top row of the first board, with 4+5+6+7+8+9 left unmarked
+        assert_eq!(part_one(parsed.clone(), &rules::standard()), Some(39 * 2));
+        //and the bottom row of the second board, with 9+8+7+6+5+4 left unmarked
+        assert_eq!(part_two(parsed, &rules::standard()), Some(39 * 2));
+    }
+
+    #[test]
+    fn test_parsing_seven_by_seven() {
+        let board = (0..7)
+            .map(|row| {
+                (1..=7)
+                    .map(|column| (row * 7 + column).to_string())
+                    .collect::<Vec<String>>()
+                    .join(" ")
+            })
+            .collect::<Vec<String>>()
+            .join("\n");
+        let example_input = format!("1,2,3,4,5,6,7\n\n{}", board);
+
+        let parsed = parse_input(&example_input).expect("failed to parse 7x7 board");
+        assert_eq!(parsed.boards[0].shape(), (7, 7));
+        //the top row wins on the seventh draw, leaving 8 through 49 unmarked
+        assert_eq!(
+            part_one(parsed, &rules::standard()),
+            Some((8..=49).sum::<Score>() * 7)
+        );
+    }
+
+    #[test]
+    fn test_parsing_rejects_mismatched_boards() {
+        let mismatched_shapes = "1,2\n\n1 2\n3 4\n\n1 2 3\n4 5 6\n7 8 9";
+        assert!(parse_input(mismatched_shapes).is_err());
+
+        let ragged_rows = "1,2\n\n1 2\n3 4 5";
+        assert!(parse_input(ragged_rows).is_err());
+    }
+
+    #[test]
+    fn test_other_win_rules() {
+        let three_by_three = |numbers: &str| {
+            parse_input(&format!("{}\n\n1 2 3\n4 5 6\n7 8 9", numbers))
+                .expect("failed to parse 3x3 board")
+        };
+
+        //9 finishes the diagonal, leaving 2+3+4+6+7+8 unmarked
+        assert_eq!(part_one(three_by_three("5,1,9"), &Diagonals), Some(30 * 9));
+        assert!(rules::parse_rule("diagonals")
+            .unwrap()
+            .validate((3, 4))
+            .is_err());
+
+        //7 is the last corner, leaving 2+4+5+6+8 unmarked
+        assert_eq!(
+            part_one(three_by_three("1,3,9,7"), &FourCorners),
+            Some(25 * 7)
+        );
+
+        let corners_and_middle = rules::parse_rule("all(four-corners,mask:000/010/000)").unwrap();
+        assert_eq!(
+            part_one(three_by_three("1,3,9,7,5"), corners_and_middle.as_ref()),
+            Some(20 * 5)
+        );
+    }
+
+    #[test]
+    fn test_no_board_wins() {
+        let input = parse_input("1,4\n\n1 2\n3 4").expect("failed to parse board");
+        assert_eq!(part_one(input.clone(), &rules::standard()), None);
+        assert_eq!(part_two(input, &rules::standard()), None);
+    }
+
+    #[test]
+    fn test_number_on_a_board_twice() {
+        //both 1s get marked on the draw that wins, so neither counts towards the score
+        let input = parse_input("2,1\n\n1 2\n1 3").expect("failed to parse board");
+        assert_eq!(part_one(input, &rules::standard()), Some(3));
+    }
+
+    #[test]
+    fn test_numbers_past_255() {
+        let input = parse_input("1000,999,2\n\n1000 256\n999 300").expect("failed to parse board");
+        //1000 and 999 finish the first column, leaving 256 + 300 unmarked
+        assert_eq!(part_one(input, &rules::standard()), Some(556 * 999));
+    }
+
+    #[test]
+    fn test_score_does_not_overflow() {
+        let max = BingoNumber::MAX;
+        let input = parse_input(&format!("{0}\n\n{0} {0}\n{1} {2}", max, max - 1, max - 2))
+            .expect("failed to parse board");
+        //the unmarked sum times the last number is well past u64::MAX
+        let expected = (2 * max as Score - 3) * max as Score;
+        assert!(expected > u64::MAX as Score);
+        assert_eq!(part_one(input, &Rows), Some(expected));
+    }
+}
@@ -1,287 +1,246 @@
-use anyhow::Result;
-use ndarray::Array2;
+use anyhow::{bail, Result};
+use clap::{Parser, Subcommand};
+use common::solution::Solution;
+use day_four::bingo::analysis;
+use day_four::bingo::generate::{self, GenerateOptions};
+use day_four::bingo::render::{self, BoardView, Style};
+use day_four::bingo::rules;
+use day_four::bingo::validate::{self, DuplicatePolicy};
+use day_four::{
+    parse_input, part_one, part_two, BingoGame, DayFour, GameEvent, InputNumbersAndBoards, Score,
+    WinRule,
+};
+use std::thread;
+use std::time::Duration;
+
+#[derive(Parser, Debug)]
+#[command(about = DayFour::TITLE)]
+struct Args {
+    /// Win rule to play with instead of the puzzle's full rows or columns, e.g. `rows`,
+    /// `any(rows,columns,diagonals)`, `all(four-corners,blackout)` or
+    /// `mask:10001/01010/00100/01010/10001`
+    #[arg(long, global = true)]
+    rule: Option<String>,
+
+    /// What to do about numbers repeated on a board or in the draws, warn if not given
+    #[arg(long, value_enum, global = true)]
+    duplicates: Option<DuplicatePolicy>,
+
+    /// Play the game out draw by draw before answering, showing every board as it's marked
+    #[arg(long, global = true)]
+    watch: bool,
+
+    /// Milliseconds to wait between draws when watching
+    #[arg(long, default_value_t = 200, global = true)]
+    watch_delay: u64,
+
+    #[command(subcommand)]
+    command: Option<Command>,
+}
+
+#[derive(Subcommand, Debug)]
+enum Command {
+    /// Answer both parts of the puzzle, what runs when no command is given
+    Solve,
+    /// Report the draw every board wins on, its score and its rank
+    Analyze {
+        /// Also play this many games with the draws shuffled, and report every board's odds of
+        /// winning first or last
+        #[arg(long)]
+        trials: Option<usize>,
+
+        /// Seed for shuffling the draws, the same seed always gives the same odds
+        #[arg(long, default_value_t = 2021)]
+        seed: u64,
+    },
+    /// Write a random puzzle input to stdout and its answers to stderr, for the standard rule
+    Generate(GenerateOptions),
+}
 
 fn main() -> Result<()> {
+    let args = Args::parse();
+    let command = args.command.unwrap_or(Command::Solve);
+    if let Command::Generate(options) = &command {
+        if args.watch {
+            bail!("--watch plays an input, it can't be used with generate");
+        }
+        if args.rule.is_some() {
+            bail!("generate only answers for the standard rule, it can't be used with --rule");
+        }
+        if args.duplicates.is_some() {
+            bail!("--duplicates checks an input, it can't be used with generate");
+        }
+        let puzzle = generate::generate(options)?;
+        print!("{}", puzzle.text);
+        eprintln!("part one: {:?}", puzzle.part_one);
+        eprintln!("part two: {:?}", puzzle.part_two);
+        return Ok(());
+    }
+
+    let rule = match args.rule {
+        Some(spec) => rules::parse_rule(&spec)?,
+        None => Box::new(rules::standard()),
+    };
+
     println!("Reading input...");
     let raw_input = common::read_input()?;
     println!("Parsing input...");
     let parsed_input = parse_input(&raw_input)?;
+    if let Some(board) = parsed_input.boards.first() {
+        rule.validate(board.shape())?;
+    }
+    validate::check_duplicates(
+        &parsed_input,
+        args.duplicates.unwrap_or(DuplicatePolicy::Warn),
+    )?;
+
+    if args.watch {
+        watch(
+            parsed_input.clone(),
+            rule.as_ref(),
+            Duration::from_millis(args.watch_delay),
+        );
+    }
 
-    println!("Part One:\n");
-    let answer = part_one(parsed_input.clone());
-    println!("Q: What will your final score be if you choose the first winning board?");
-    println!("A: {:?}", answer);
-
-    println!("\n\nPart Two:\n");
-    let answer = part_two(parsed_input.clone());
-    println!("Q: Once it wins, what would its final score be?");
-    println!("A: {:?}", answer);
-
-    Ok(())
-}
-
-#[derive(Debug, PartialEq, Clone, Default)]
-struct BingoSlot {
-    number: u8,
-    marked: bool,
-}
-
-impl BingoSlot {
-    fn new_unmarked_with_number(number: u8) -> Self {
-        BingoSlot {
-            number,
-            marked: false,
+    match command {
+        Command::Solve => solve(parsed_input, rule.as_ref()),
+        Command::Analyze { trials, seed } => {
+            print_analysis(parsed_input, rule.as_ref(), trials, seed)
         }
+        Command::Generate(_) => unreachable!("generating doesn't read an input"),
     }
-}
 
-#[derive(Debug, PartialEq, Clone)]
-struct InputNumbersAndBoards {
-    numbers: Vec<u8>,
-    boards: Vec<Array2<BingoSlot>>,
+    Ok(())
 }
 
-fn parse_input(raw_input: &str) -> Result<InputNumbersAndBoards> {
-    let mut input_numbers = Vec::new();
-    let mut num_vertical_lines_on_current_board = 0;
-    let mut boards = Vec::new();
-    let mut working_board = Array2::default((5, 5));
-    for (index, mut line) in raw_input.lines().enumerate() {
-        line = line.trim();
-        match index {
-            0 => {
-                //parse the input numbers
-                input_numbers = line
-                    .split(",")
-                    .map(|s| {
-                        s.parse::<u8>()
-                            .expect("failed to parse an input number to a u8")
-                    })
-                    .collect()
-            }
-            1 => continue, //empty line before the boards start
-            _ => {
-                if line.is_empty() {
-                    if num_vertical_lines_on_current_board != 5 {
-                        panic!("we hit an empty line, past index 1, but we haven't read four vertical lines or \
-                        board numbers yet.. this should never happen, check your input")
-                    } else {
-                        //reset it and move to the next line if there is one where we expect a new board
-                        num_vertical_lines_on_current_board = 0;
-                        continue;
-                    }
-                }
-
-                //we know we are reading a board
-                for (i, n) in line
-                    .split_whitespace()
-                    .map(|s| {
-                        s.parse::<u8>()
-                            .expect("failed to parse number in board to u8")
-                    })
-                    .enumerate()
-                {
-                    //fill in all the numbers for the row we are now
-                    working_board.row_mut(num_vertical_lines_on_current_board)[i] =
-                        BingoSlot::new_unmarked_with_number(n);
-                }
+fn solve(parsed_input: InputNumbersAndBoards, rule: &dyn WinRule) {
+    println!("Part One:\n");
+    let answer = part_one(parsed_input.clone(), rule);
+    println!("Q: {}", DayFour::PART_ONE_QUESTION);
+    print_answer(answer);
 
-                //now that we are done reading that row, mark the counter up
-                num_vertical_lines_on_current_board += 1;
+    println!("\n\nPart Two:\n");
+    let answer = part_two(parsed_input, rule);
+    println!("Q: {}", DayFour::PART_TWO_QUESTION);
+    print_answer(answer);
+}
 
-                //if we have hit 5, copy the working board into our vec of all the boards
-                if num_vertical_lines_on_current_board == 5 {
-                    boards.push(working_board.clone())
-                }
-            }
-        }
+fn print_answer(answer: Option<Score>) {
+    match answer {
+        Some(score) => println!("A: {:?}", score),
+        None => println!("A: no board wins"),
     }
-    Ok(InputNumbersAndBoards {
-        numbers: input_numbers,
-        boards,
-    })
 }
 
-fn play_game(mut input: InputNumbersAndBoards) -> Vec<(usize, Array2<BingoSlot>, u8)> {
-    let mut winning_boards_with_final_number_and_index =
-        Vec::<(usize, Array2<BingoSlot>, u8)>::new();
-
-    for current_bingo_number in &input.numbers {
-        for board in &mut input.boards {
-            for slot in board.iter_mut() {
-                if slot.number == *current_bingo_number {
-                    slot.marked = true;
+/// Redraws every board after each draw, winning boards with the slots that won them picked out.
+fn watch(parsed_input: InputNumbersAndBoards, rule: &dyn WinRule, delay: Duration) {
+    const BOARDS_PER_LINE: usize = 5;
+
+    let style = Style::detect();
+    let mut game = BingoGame::new(parsed_input, rule);
+    let mut winning_slots = vec![None; game.boards().len()];
+    let mut wins = Vec::new();
+    while let Some(events) = game.next_draw() {
+        let mut drawn = None;
+        for event in events {
+            match event {
+                GameEvent::NumberDrawn { draw_index, number } => drawn = Some((draw_index, number)),
+                GameEvent::BoardWon { board, score, .. } => {
+                    winning_slots[board] = Some(rule.winning_slots(&game.boards()[board]));
+                    wins.push((board, score));
                 }
+                GameEvent::CellMarked { .. } => {}
             }
         }
 
-        for (board_index, board) in input.boards.iter().enumerate() {
-            if is_win(board) {
-                //check if it's already in our winners list
-                if let None = winning_boards_with_final_number_and_index
-                    .iter()
-                    .find(|(i, b, number)| *i == board_index)
-                {
-                    winning_boards_with_final_number_and_index.push((
-                        board_index,
-                        board.clone(),
-                        *current_bingo_number,
-                    ))
+        let views = game
+            .boards()
+            .iter()
+            .zip(&winning_slots)
+            .enumerate()
+            .map(|(index, (board, winning))| {
+                let view = BoardView::new(board, style);
+                match winning {
+                    Some(winning) => (format!("Board {} (won)", index), view.with_winning(winning)),
+                    None => (format!("Board {}", index), view),
                 }
-            }
-        }
-    }
-
-    winning_boards_with_final_number_and_index
-}
+            })
+            .collect::<Vec<_>>();
 
-fn part_one(mut input: InputNumbersAndBoards) -> u64 {
-    let winning_boards = play_game(input);
-    let (_, board, winning_number) = winning_boards.first().expect("no winning boards");
-    return calculate_winning_board_score(board, *winning_number);
-}
-
-fn part_two(mut input: InputNumbersAndBoards) -> u64 {
-    let winning_boards = play_game(input);
-    let (_, board, winning_number) = winning_boards.last().expect("no winning boards");
-    return calculate_winning_board_score(board, *winning_number);
-}
-
-fn is_win(board: &Array2<BingoSlot>) -> bool {
-    let mut num_marked = 0;
-    let mut winning = false;
-    for row in board.rows() {
-        for slot in row {
-            if slot.marked == true {
-                num_marked += 1;
-            }
+        match style {
+            Style::Ansi => print!("{}", render::CLEAR_SCREEN),
+            Style::Plain => println!("{}", "-".repeat(40)),
         }
-        if num_marked == 5 {
-            //bingo!
-            winning = true;
-            break;
-        } else {
-            //reset counter
-            num_marked = 0;
+        if let Some((draw_index, number)) = drawn {
+            println!("Draw {}: {}\n", draw_index + 1, number);
         }
-    }
-    for column in board.columns() {
-        for slot in column {
-            if slot.marked == true {
-                num_marked += 1;
-            }
-        }
-        if num_marked == 5 {
-            //bingo!
-            winning = true;
-            break;
-        } else {
-            //reset counter
-            num_marked = 0;
+        print!("{}", render::side_by_side(&views, BOARDS_PER_LINE));
+        for (board, score) in &wins {
+            println!("Board {} won, scoring {}", board, score);
         }
+        thread::sleep(delay);
     }
-    winning
-}
-
-fn calculate_winning_board_score(board: &Array2<BingoSlot>, winning_number: u8) -> u64 {
-    let summed = board.into_iter().fold(0u64, |sum, slot| {
-        if !slot.marked {
-            sum + slot.number as u64
-        } else {
-            sum
-        }
-    });
-    summed as u64 * winning_number as u64
+    println!();
 }
 
-#[cfg(test)]
-mod test {
-    use crate::{parse_input, part_one, part_two, BingoSlot, InputNumbersAndBoards};
-    use ndarray::array;
-
-    fn test_input() -> InputNumbersAndBoards {
-        let board_1 = array![
-            [22u8, 13, 17, 11, 0],
-            [8, 2, 23, 4, 24],
-            [21, 9, 14, 16, 7],
-            [6, 10, 3, 18, 5],
-            [1, 12, 20, 15, 19]
-        ]
-        .mapv(|n| BingoSlot::new_unmarked_with_number(n));
-
-        let board_2 = array![
-            [3u8, 15, 0, 2, 22],
-            [9, 18, 13, 17, 5],
-            [19, 8, 7, 25, 23],
-            [20, 11, 10, 24, 4],
-            [14, 21, 16, 12, 6],
-        ]
-        .mapv(|n| BingoSlot::new_unmarked_with_number(n));
-
-        let board_3 = array![
-            [14u8, 21, 17, 24, 4],
-            [10, 16, 15, 9, 19],
-            [18, 8, 23, 26, 20],
-            [22, 11, 13, 6, 5],
-            [2, 0, 12, 3, 7],
-        ]
-        .mapv(|n| BingoSlot::new_unmarked_with_number(n));
-
-        InputNumbersAndBoards {
-            numbers: vec![
-                7, 4, 9, 5, 11, 17, 23, 2, 0, 14, 21, 24, 10, 16, 13, 6, 15, 25, 12, 22, 18, 20, 8,
-                19, 3, 26, 1,
-            ],
-            boards: vec![board_1, board_2, board_3],
+fn print_analysis(
+    parsed_input: InputNumbersAndBoards,
+    rule: &dyn WinRule,
+    trials: Option<usize>,
+    seed: u64,
+) {
+    println!("\nEvery board under the draws as given:\n");
+    println!("{:>6} {:>6} {:>6} {:>12}", "board", "rank", "draw", "score");
+    let mut outcomes = analysis::analyze(parsed_input.clone(), rule);
+    //winners in the order they win, then the boards that never do
+    outcomes.sort_by_key(|outcome| (outcome.rank.is_none(), outcome.rank, outcome.board));
+    for outcome in outcomes {
+        match outcome.win.zip(outcome.rank) {
+            Some(((draw_index, score), rank)) => println!(
+                "{:>6} {:>6} {:>6} {:>12}",
+                outcome.board, rank, draw_index, score
+            ),
+            None => println!(
+                "{:>6} {:>6} {:>6} {:>12}",
+                outcome.board, "-", "-", "never wins"
+            ),
         }
     }
 
-    #[test]
-    fn test_parsing() {
-        let example_input = r#"
-        
-        7,4,9,5,11,17,23,2,0,14,21,24,10,16,13,6,15,25,12,22,18,20,8,19,3,26,1
-
-        22 13 17 11  0
-         8  2 23  4 24
-        21  9 14 16  7
-         6 10  3 18  5
-         1 12 20 15 19
-        
-         3 15  0  2 22
-         9 18 13 17  5
-        19  8  7 25 23
-        20 11 10 24  4
-        14 21 16 12  6
-        
-        14 21 17 24  4
-        10 16 15  9 19
-        18  8 23 26 20
-        22 11 13  6  5
-         2  0 12  3  7
-        
-        "#
-        .trim();
-
-        let parsed = parse_input(&example_input)
-            .expect("failed to parse input numbers and boards in test example");
-
-        let expected = test_input();
-
-        assert_eq!(expected, parsed)
-    }
-
-    #[test]
-    fn test_part_one() {
-        let input = test_input();
-        let answer = part_one(input);
-        assert_eq!(answer, 4512);
+    let trials = match trials {
+        Some(trials) if trials > 0 => trials,
+        _ => return,
+    };
+    println!(
+        "\nOdds over {} games with the draws shuffled (seed {}):\n",
+        trials, seed
+    );
+    println!("{:>6} {:>8} {:>8} {:>8}", "board", "first", "last", "never");
+    let odds = analysis::monte_carlo(&parsed_input, rule, trials, seed);
+    let percent = |count: usize| 100.0 * count as f64 / trials as f64;
+    for board_odds in &odds {
+        println!(
+            "{:>6} {:>7.1}% {:>7.1}% {:>7.1}%",
+            board_odds.board,
+            percent(board_odds.first),
+            percent(board_odds.last),
+            percent(board_odds.never)
+        );
     }
 
-    #[test]
-    fn test_part_two() {
-        let input = test_input();
-        let answer = part_two(input);
-        assert_eq!(answer, 1924);
+    //ties go to the lowest board index, same as the puzzle picking the first winner
+    let best_first = odds.iter().rev().max_by_key(|board_odds| board_odds.first);
+    let best_last = odds.iter().rev().max_by_key(|board_odds| board_odds.last);
+    if let (Some(best_first), Some(best_last)) = (best_first, best_last) {
+        println!(
+            "\nBest pick to win first: board {} ({:.1}%)",
+            best_first.board,
+            percent(best_first.first)
+        );
+        println!(
+            "Best pick to win last: board {} ({:.1}%)",
+            best_last.board,
+            percent(best_last.last)
+        );
     }
 }
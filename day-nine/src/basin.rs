@@ -0,0 +1,122 @@
+//! Splitting the height map into basins, the areas that drain down to each low point.
+
+use std::fmt;
+
+use common::grid::Grid;
+
+/// Height 9 is never part of a basin, it's the walls between them.
+pub const RIDGE: u8 = 9;
+
+/// Cells lower than every one of their orthogonal neighbours, in reading order.
+pub fn low_points(heights: &Grid<u8>) -> Vec<(usize, usize)> {
+    heights
+        .indexed_iter()
+        .filter(|&((row, column), &height)| {
+            heights
+                .neighbors(row, column)
+                .all(|neighbor| heights[neighbor] > height)
+        })
+        .map(|(cell, _)| cell)
+        .collect()
+}
+
+/// Which basin every cell is in, numbered in the order of [`low_points`]. Low points that share a
+/// basin, like two 0s either side of a 1, count as one.
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct Basins {
+    /// `None` for the ridges, and for any cell no low point drains from.
+    pub labels: Grid<Option<usize>>,
+    pub sizes: Vec<usize>,
+}
+
+impl Basins {
+    /// Flood fills out from every low point until it hits the ridges.
+    ///
+    /// The fill keeps its own stack of cells still to visit rather than recursing, so a basin
+    /// as big as the whole map can't run out of call stack.
+    pub fn find(heights: &Grid<u8>) -> Self {
+        let (num_rows, num_columns) = heights.shape();
+        let mut labels = Grid::filled(num_rows, num_columns, None);
+        let mut sizes = Vec::new();
+        let mut stack = Vec::new();
+        for low_point in low_points(heights) {
+            //already filled from an earlier low point in the same basin
+            if labels[low_point].is_some() {
+                continue;
+            }
+            let label = sizes.len();
+            let mut size = 0;
+            labels[low_point] = Some(label);
+            stack.push(low_point);
+            while let Some((row, column)) = stack.pop() {
+                size += 1;
+                for neighbor in heights.neighbors(row, column) {
+                    if heights[neighbor] != RIDGE && labels[neighbor].is_none() {
+                        labels[neighbor] = Some(label);
+                        stack.push(neighbor);
+                    }
+                }
+            }
+            sizes.push(size);
+        }
+        Basins { labels, sizes }
+    }
+
+    /// The sizes of the `n` biggest basins, biggest first.
+    pub fn largest(&self, n: usize) -> Vec<usize> {
+        let mut sizes = self.sizes.clone();
+        sizes.sort_unstable_by(|a, b| b.cmp(a));
+        sizes.truncate(n);
+        sizes
+    }
+}
+
+/// The label of every cell, `.` for the ones outside any basin.
+impl fmt::Display for Basins {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let labels = self.labels.map(|label| match label {
+            Some(label) => label.to_string(),
+            None => ".".to_string(),
+        });
+        write!(f, "{}", labels)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use common::parse::digit_grid;
+
+    use crate::basin::{low_points, Basins};
+
+    #[test]
+    fn test_one_basin_filling_the_map() {
+        //big enough that a recursive fill would risk the stack in a debug build
+        let input = format!("{}\n", "5".repeat(400)).repeat(400);
+        let heights = digit_grid(&input).unwrap();
+        assert_eq!(low_points(&heights), vec![]);
+
+        let mut heights = heights;
+        heights[(200, 200)] = 0;
+        let basins = Basins::find(&heights);
+        assert_eq!(basins.sizes, vec![400 * 400]);
+        assert!(basins.labels.iter().all(|&label| label == Some(0)));
+    }
+
+    #[test]
+    fn test_labels() {
+        let heights = digit_grid("191\n999\n109").unwrap();
+        let basins = Basins::find(&heights);
+        assert_eq!(basins.sizes, vec![1, 1, 2]);
+        assert_eq!(basins.largest(2), vec![2, 1]);
+        assert_eq!(basins.to_string(), "0 . 1\n. . .\n2 2 .\n");
+    }
+
+    #[test]
+    fn test_low_points_sharing_a_basin() {
+        let heights = digit_grid("010\n999").unwrap();
+        assert_eq!(low_points(&heights), vec![(0, 0), (0, 2)]);
+        let basins = Basins::find(&heights);
+        assert_eq!(basins.sizes, vec![3]);
+        assert_eq!(basins.to_string(), "0 0 0\n. . .\n");
+    }
+}
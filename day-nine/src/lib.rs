@@ -0,0 +1,71 @@
+//! Day 9: Smoke Basin, finding the low points of the cave floor and the basins draining to them.
+
+pub mod basin;
+
+use anyhow::Result;
+use common::grid::Grid;
+use common::parse::{digit_grid, ParseError};
+use common::solution::Solution;
+
+pub use basin::{low_points, Basins};
+
+/// A height from 0 to 9 for every spot on the floor.
+pub fn parse_input(raw_input: &str) -> Result<Grid<u8>, ParseError> {
+    digit_grid(raw_input)
+}
+
+/// The sum of the risk levels, one more than the height, of every low point.
+pub fn part_one(heights: &Grid<u8>) -> u32 {
+    low_points(heights)
+        .into_iter()
+        .map(|low_point| heights[low_point] as u32 + 1)
+        .sum()
+}
+
+/// The sizes of the three largest basins multiplied together.
+pub fn part_two(heights: &Grid<u8>) -> usize {
+    Basins::find(heights).largest(3).iter().product()
+}
+
+pub struct DayNine;
+
+impl Solution for DayNine {
+    const TITLE: &'static str = "Day 9: Smoke Basin";
+    const PART_ONE_QUESTION: &'static str =
+        "What is the sum of the risk levels of all low points on your heightmap?";
+    const PART_TWO_QUESTION: &'static str =
+        "What do you get if you multiply together the sizes of the three largest basins?";
+
+    type Input = Grid<u8>;
+    type PartOne = u32;
+    type PartTwo = usize;
+
+    fn parse(raw_input: &str) -> Result<Grid<u8>> {
+        Ok(parse_input(raw_input)?)
+    }
+
+    fn part_one(heights: &Grid<u8>) -> Result<u32> {
+        Ok(part_one(heights))
+    }
+
+    fn part_two(heights: &Grid<u8>) -> Result<usize> {
+        Ok(part_two(heights))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{low_points, parse_input, part_one, part_two, Basins};
+
+    //the example from the puzzle description
+    const EXAMPLE: &str = include_str!("../example");
+
+    #[test]
+    fn test_example() {
+        let heights = parse_input(EXAMPLE).expect("failed to parse example");
+        assert_eq!(low_points(&heights), vec![(0, 1), (0, 9), (2, 2), (4, 6)]);
+        assert_eq!(part_one(&heights), 15);
+        assert_eq!(Basins::find(&heights).sizes, vec![3, 9, 14, 9]);
+        assert_eq!(part_two(&heights), 1134);
+    }
+}
@@ -0,0 +1,24 @@
+use anyhow::Result;
+use clap::Parser;
+use common::solution::{self, Solution};
+use day_nine::{parse_input, Basins, DayNine};
+
+#[derive(Parser, Debug)]
+#[command(about = DayNine::TITLE)]
+struct Args {
+    /// Print the basin label of every cell instead of solving, `.` for the ridges
+    #[arg(long)]
+    labels: bool,
+}
+
+fn main() -> Result<()> {
+    let args = Args::parse();
+    if !args.labels {
+        return solution::run::<DayNine>();
+    }
+
+    let raw_input = common::read_input()?;
+    let heights = parse_input(&raw_input)?;
+    print!("{}", Basins::find(&heights));
+    Ok(())
+}
@@ -0,0 +1,140 @@
+//! Day 1: Sonar Sweep, counting how often the sea floor gets deeper.
+
+use anyhow::Result;
+use common::parse::{lines_of, ParseError};
+use common::solution::Solution;
+
+pub struct DayOne;
+
+impl Solution for DayOne {
+    const TITLE: &'static str = "Day 1: Sonar Sweep";
+    const PART_ONE_QUESTION: &'static str =
+        "How many measurements are larger than the previous measurement?";
+    const PART_TWO_QUESTION: &'static str = "How many sums are larger than the previous sum?";
+
+    type Input = Vec<i32>;
+    type PartOne = i32;
+    type PartTwo = i32;
+
+    fn parse(raw_input: &str) -> Result<Vec<i32>> {
+        Ok(parse_input(raw_input)?)
+    }
+
+    fn part_one(numbers: &Vec<i32>) -> Result<i32> {
+        if cfg!(feature = "parallel") {
+            Ok(part_one_parallel(numbers))
+        } else {
+            Ok(part_one(numbers))
+        }
+    }
+
+    fn part_two(numbers: &Vec<i32>) -> Result<i32> {
+        if cfg!(feature = "parallel") {
+            Ok(part_two_parallel(numbers))
+        } else {
+            Ok(part_two(numbers))
+        }
+    }
+}
+
+/// One depth measurement per line.
+pub fn parse_input(input: &str) -> Result<Vec<i32>, ParseError> {
+    lines_of::<i32>(input)
+}
+
+/// How many measurements are larger than the one before.
+pub fn part_one(numbers: &[i32]) -> i32 {
+    let mut count_greater_than_previous = 0;
+    let mut last_num = None;
+
+    for number in numbers {
+        //skip the first item
+        if let Some(last_number_value) = last_num {
+            if number > last_number_value {
+                count_greater_than_previous += 1;
+            }
+        }
+        last_num = Some(number);
+    }
+
+    count_greater_than_previous
+}
+
+/// How many sums of three consecutive measurements are larger than the sum before.
+pub fn part_two(numbers: &[i32]) -> i32 {
+    let mut sum_count_greater_than_previous = 0;
+    let mut last_sum = None;
+
+    for nums in numbers.windows(3) {
+        let new_sum = nums[0] + nums[1] + nums[2];
+        if let Some(last_sum_value) = last_sum {
+            if new_sum > last_sum_value {
+                sum_count_greater_than_previous += 1;
+            }
+        }
+        last_sum = Some(new_sum)
+    }
+
+    sum_count_greater_than_previous
+}
+
+//how many indexes each chunk covers when counting in parallel
+const CHUNK_SIZE: usize = 4096;
+
+//counts the indexes where numbers[i + gap] > numbers[i], split into chunks that are counted in
+//parallel when the "parallel" feature is on
+fn count_increases_chunked(numbers: &[i32], gap: usize) -> i32 {
+    let len = numbers.len().saturating_sub(gap);
+    common::parallel::map_reduce_ranges(
+        len,
+        CHUNK_SIZE,
+        || 0,
+        |range| range.filter(|&i| numbers[i + gap] > numbers[i]).count() as i32,
+        |a, b| a + b,
+    )
+}
+
+/// Same answer as [`part_one`], counted in chunks that run in parallel with the `parallel`
+/// feature.
+pub fn part_one_parallel(numbers: &[i32]) -> i32 {
+    count_increases_chunked(numbers, 1)
+}
+
+/// Same answer as [`part_two`], counted in chunks that run in parallel with the `parallel`
+/// feature.
+pub fn part_two_parallel(numbers: &[i32]) -> i32 {
+    //consecutive three-sums share two numbers, so the next sum is larger exactly when the
+    //number entering the window is larger than the one leaving it
+    count_increases_chunked(numbers, 3)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{part_one, part_one_parallel, part_two, part_two_parallel};
+
+    #[test]
+    fn test_part_one() {
+        let nums = [1, 2, 5, 5, 2];
+        let answer = part_one(&nums);
+        assert_eq!(answer, 2); //calc by hand
+    }
+
+    #[test]
+    fn test_part_two() {
+        let nums = [85, 120, 194, 21, 15, 250, 354, 908, 342];
+        let answer = part_two(&nums);
+        assert_eq!(answer, 4); //calc by hand
+    }
+
+    #[test]
+    fn test_parallel_matches_sequential() {
+        //a few chunks worth of numbers, so chunk boundaries get crossed
+        let nums = (0..20_000i32)
+            .map(|n| n.wrapping_mul(48271) % 1000)
+            .collect::<Vec<i32>>();
+        assert_eq!(part_one_parallel(&nums), part_one(&nums));
+        assert_eq!(part_two_parallel(&nums), part_two(&nums));
+        assert_eq!(part_one_parallel(&[]), part_one(&[]));
+        assert_eq!(part_two_parallel(&[1, 2]), part_two(&[1, 2]));
+    }
+}
@@ -0,0 +1,187 @@
+//! What moving a crab costs, and finding the position that costs the least for all of them.
+
+/// The fuel a crab burns to move `distance` steps.
+///
+/// The search in [`cheapest_position`] relies on the cost never going down as the distance goes
+/// up, and on every extra step costing at least as much as the one before (convexity).
+pub trait FuelCost {
+    fn cost(&self, distance: u64) -> u64;
+}
+
+/// Every step costs 1.
+#[derive(Debug, Clone, Copy)]
+pub struct Linear;
+
+impl FuelCost for Linear {
+    fn cost(&self, distance: u64) -> u64 {
+        distance
+    }
+}
+
+/// Every step costs 1 more than the last, so `n` steps cost `1 + 2 + ... + n`.
+#[derive(Debug, Clone, Copy)]
+pub struct Triangular;
+
+impl FuelCost for Triangular {
+    fn cost(&self, distance: u64) -> u64 {
+        distance * (distance + 1) / 2
+    }
+}
+
+/// Any function of the distance works as a cost, e.g. `|distance| distance * distance`.
+impl<F: Fn(u64) -> u64> FuelCost for F {
+    fn cost(&self, distance: u64) -> u64 {
+        self(distance)
+    }
+}
+
+/// Where the crabs line up and what it costs them.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub struct Alignment {
+    pub position: i64,
+    pub fuel: u64,
+}
+
+/// The fuel for every crab to move to `target`.
+pub fn total_fuel(positions: &[i64], target: i64, cost: &impl FuelCost) -> u64 {
+    positions
+        .iter()
+        .map(|&position| cost.cost(position.abs_diff(target)))
+        .sum()
+}
+
+/// The lowest point of a convex function over `low..=high`, the leftmost one if there's a tie.
+///
+/// Convex means the differences `f(x + 1) - f(x)` never go down, so a binary search for the first
+/// `x` where the function stops falling finds the bottom.
+pub fn minimize_convex(low: i64, high: i64, f: impl Fn(i64) -> u64) -> (i64, u64) {
+    assert!(low <= high, "can't minimize over an empty range");
+    let (mut low, mut high) = (low, high);
+    while low < high {
+        let middle = low + (high - low) / 2;
+        if f(middle) <= f(middle + 1) {
+            high = middle;
+        } else {
+            low = middle + 1;
+        }
+    }
+    (low, f(low))
+}
+
+/// The cheapest position between the outermost crabs for any convex cost, by [`minimize_convex`].
+pub fn cheapest_position(positions: &[i64], cost: &impl FuelCost) -> Option<Alignment> {
+    let low = *positions.iter().min()?;
+    let high = *positions.iter().max()?;
+    let (position, fuel) = minimize_convex(low, high, |target| total_fuel(positions, target, cost));
+    Some(Alignment { position, fuel })
+}
+
+/// With linear costs the median is always a cheapest position: moving away from it brings at
+/// least as many crabs further as it brings closer.
+pub fn align_linear(positions: &[i64]) -> Option<Alignment> {
+    if positions.is_empty() {
+        return None;
+    }
+    let mut sorted = positions.to_vec();
+    let middle = (sorted.len() - 1) / 2;
+    let position = *sorted.select_nth_unstable(middle).1;
+    Some(Alignment {
+        position,
+        fuel: total_fuel(positions, position, &Linear),
+    })
+}
+
+/// With triangular costs the cheapest position is always within half a step of the mean, so
+/// only the positions either side of it need trying.
+pub fn align_triangular(positions: &[i64]) -> Option<Alignment> {
+    if positions.is_empty() {
+        return None;
+    }
+    let mean = positions
+        .iter()
+        .sum::<i64>()
+        .div_euclid(positions.len() as i64);
+    (mean - 1..=mean + 1)
+        .map(|position| Alignment {
+            position,
+            fuel: total_fuel(positions, position, &Triangular),
+        })
+        .min_by_key(|alignment| (alignment.fuel, alignment.position))
+}
+
+#[cfg(test)]
+mod test {
+    use crate::fuel::{
+        align_linear, align_triangular, cheapest_position, minimize_convex, total_fuel, Linear,
+        Triangular,
+    };
+
+    fn brute_force(positions: &[i64], cost: impl Fn(u64) -> u64) -> u64 {
+        let (low, high) = (
+            *positions.iter().min().unwrap(),
+            *positions.iter().max().unwrap(),
+        );
+        (low..=high)
+            .map(|target| total_fuel(positions, target, &cost))
+            .min()
+            .unwrap()
+    }
+
+    #[test]
+    fn test_minimize_convex() {
+        assert_eq!(minimize_convex(-10, 10, |x| (x - 3).unsigned_abs()), (3, 0));
+        //a flat bottom gives its leftmost point
+        assert_eq!(
+            minimize_convex(0, 10, |x| (x - 4).max(0).unsigned_abs()
+                + (2 - x).max(0) as u64),
+            (2, 0)
+        );
+        assert_eq!(minimize_convex(5, 5, |x| x as u64), (5, 5));
+    }
+
+    #[test]
+    fn test_closed_forms_match_the_search() {
+        for seed in 1..50u64 {
+            //a few crabs in a clump plus some stragglers, from a small lcg
+            let mut state = seed;
+            let positions = (0..(seed % 17 + 1))
+                .map(|_| {
+                    state = state
+                        .wrapping_mul(6364136223846793005)
+                        .wrapping_add(1442695040888963407);
+                    ((state >> 33) % 2000) as i64 - 500
+                })
+                .collect::<Vec<i64>>();
+
+            let linear = align_linear(&positions).unwrap();
+            assert_eq!(
+                linear.fuel,
+                cheapest_position(&positions, &Linear).unwrap().fuel
+            );
+            assert_eq!(linear.fuel, brute_force(&positions, |d| d));
+
+            let triangular = align_triangular(&positions).unwrap();
+            assert_eq!(
+                triangular.fuel,
+                cheapest_position(&positions, &Triangular).unwrap().fuel
+            );
+            assert_eq!(
+                triangular.fuel,
+                brute_force(&positions, |d| d * (d + 1) / 2)
+            );
+
+            let cubed = |d: u64| d * d * d;
+            assert_eq!(
+                cheapest_position(&positions, &cubed).unwrap().fuel,
+                brute_force(&positions, cubed)
+            );
+        }
+    }
+
+    #[test]
+    fn test_no_crabs() {
+        assert_eq!(align_linear(&[]), None);
+        assert_eq!(align_triangular(&[]), None);
+        assert_eq!(cheapest_position(&[], &Linear), None);
+    }
+}
@@ -0,0 +1,81 @@
+//! Day 7: The Treachery of Whales, lining the crab submarines up for the least fuel.
+
+pub mod fuel;
+
+use anyhow::{Context, Result};
+use common::parse::{comma_separated, ParseError};
+use common::solution::Solution;
+
+pub use fuel::{Alignment, FuelCost, Linear, Triangular};
+
+/// A single line of comma separated horizontal positions.
+pub fn parse_input(raw_input: &str) -> Result<Vec<i64>, ParseError> {
+    comma_separated::<i64>(raw_input)
+}
+
+/// The least fuel to line up when every step costs 1, None if there are no crabs.
+pub fn part_one(positions: &[i64]) -> Option<u64> {
+    fuel::align_linear(positions).map(|alignment| alignment.fuel)
+}
+
+/// The least fuel to line up when every step costs 1 more than the last, None if there are no
+/// crabs.
+pub fn part_two(positions: &[i64]) -> Option<u64> {
+    fuel::align_triangular(positions).map(|alignment| alignment.fuel)
+}
+
+pub struct DaySeven;
+
+impl Solution for DaySeven {
+    const TITLE: &'static str = "Day 7: The Treachery of Whales";
+    const PART_ONE_QUESTION: &'static str =
+        "How much fuel must they spend to align to that position?";
+    const PART_TWO_QUESTION: &'static str =
+        "How much fuel must they spend to align to that position?";
+
+    type Input = Vec<i64>;
+    type PartOne = u64;
+    type PartTwo = u64;
+
+    fn parse(raw_input: &str) -> Result<Vec<i64>> {
+        Ok(parse_input(raw_input)?)
+    }
+
+    fn part_one(positions: &Vec<i64>) -> Result<u64> {
+        part_one(positions).context("no crabs")
+    }
+
+    fn part_two(positions: &Vec<i64>) -> Result<u64> {
+        part_two(positions).context("no crabs")
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::fuel::{align_linear, align_triangular, Alignment};
+    use crate::{parse_input, part_one, part_two};
+
+    //the example from the puzzle description
+    const EXAMPLE: &str = include_str!("../example");
+
+    #[test]
+    fn test_example() {
+        let positions = parse_input(EXAMPLE).expect("failed to parse example");
+        assert_eq!(part_one(&positions), Some(37));
+        assert_eq!(part_two(&positions), Some(168));
+        assert_eq!(
+            align_linear(&positions),
+            Some(Alignment {
+                position: 2,
+                fuel: 37
+            })
+        );
+        assert_eq!(
+            align_triangular(&positions),
+            Some(Alignment {
+                position: 5,
+                fuel: 168
+            })
+        );
+    }
+}
@@ -0,0 +1,7 @@
+use anyhow::Result;
+use common::solution;
+use day_seven::DaySeven;
+
+fn main() -> Result<()> {
+    solution::run::<DaySeven>()
+}
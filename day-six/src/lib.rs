@@ -0,0 +1,211 @@
+//! Day 6: Lanternfish, how fast a school of lanternfish grows.
+//!
+//! Fish with the same timer all behave the same, so the school is just a count of fish per timer
+//! value, 9 numbers however big it gets. A day moves every count down a bucket, with the fish at 0
+//! going back to 6 and each adding a new fish at 8.
+
+use std::array;
+
+use anyhow::Result;
+use common::parse::{comma_separated, numbered_lines, ParseError};
+use common::solution::Solution;
+use num_bigint::BigUint;
+
+/// Timers run from 0 up to 8 for a newborn fish.
+pub const TIMER_VALUES: usize = 9;
+const RESET_TIMER: usize = 6;
+const NEWBORN_TIMER: usize = 8;
+
+/// How many fish there are with every timer value.
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Default)]
+pub struct School {
+    counts: [u64; TIMER_VALUES],
+}
+
+impl School {
+    pub fn from_timers(timers: &[u8]) -> Self {
+        let mut school = School::default();
+        for &timer in timers {
+            school.counts[timer as usize] += 1;
+        }
+        school
+    }
+
+    pub fn counts(&self) -> [u64; TIMER_VALUES] {
+        self.counts
+    }
+
+    pub fn total(&self) -> u64 {
+        self.counts.iter().sum()
+    }
+
+    /// Moves one day on, None if a count would overflow.
+    pub fn step(&self) -> Option<School> {
+        let mut counts = self.counts;
+        counts.rotate_left(1);
+        //the fish that were at 0 are now both the newborns at 8 and back at 6 themselves
+        counts[RESET_TIMER] = counts[RESET_TIMER].checked_add(counts[NEWBORN_TIMER])?;
+        Some(School { counts })
+    }
+
+    /// The school after `days`, None if it grows past `u64` (somewhere after 400 days or so).
+    pub fn after(&self, days: usize) -> Option<School> {
+        (0..days).try_fold(*self, |school, _| school.step())
+    }
+
+    /// How many fish there are after any number of days, however many digits that takes.
+    ///
+    /// A day is a fixed linear map on the counts, and by Cayley-Hamilton its matrix `M` satisfies
+    /// its characteristic polynomial, `M^9 = M^2 + I`. So `M^days` is `x^days mod (x^9 - x^2 - 1)`
+    /// evaluated at `M`, a sum of `M^0` to `M^8`. That remainder is found by squaring with only
+    /// additions to reduce, a million days is 20 squarings of 9 coefficients, and the total is the
+    /// same sum over the totals of the first 9 days.
+    pub fn total_after(&self, days: u64) -> BigUint {
+        let mut totals = Vec::with_capacity(TIMER_VALUES);
+        let mut school = *self;
+        for _ in 0..TIMER_VALUES {
+            totals.push(BigUint::from(school.total()));
+            school = school.step().expect("9 days fits in a u64");
+        }
+
+        x_to_the(days)
+            .iter()
+            .zip(&totals)
+            .map(|(coefficient, total)| coefficient * total)
+            .sum()
+    }
+}
+
+/// Coefficients of a polynomial of degree below 9, lowest first.
+type Remainder = [BigUint; TIMER_VALUES];
+
+/// `x^exponent mod (x^9 - x^2 - 1)`.
+fn x_to_the(mut exponent: u64) -> Remainder {
+    let mut result = array::from_fn(|degree| BigUint::from((degree == 0) as u8));
+    let mut base = array::from_fn(|degree| BigUint::from((degree == 1) as u8));
+    while exponent > 0 {
+        if exponent & 1 == 1 {
+            result = multiply(&result, &base);
+        }
+        exponent >>= 1;
+        if exponent > 0 {
+            base = multiply(&base, &base);
+        }
+    }
+    result
+}
+
+fn multiply(a: &Remainder, b: &Remainder) -> Remainder {
+    let mut product = vec![BigUint::from(0u8); 2 * TIMER_VALUES - 1];
+    for (i, a) in a.iter().enumerate() {
+        for (j, b) in b.iter().enumerate() {
+            product[i + j] += a * b;
+        }
+    }
+    //x^d = x^(d-7) + x^(d-9), from the highest degree down so everything ends up below 9
+    for degree in (TIMER_VALUES..product.len()).rev() {
+        let coefficient = std::mem::take(&mut product[degree]);
+        product[degree - 7] += &coefficient;
+        product[degree - 9] += coefficient;
+    }
+    product.truncate(TIMER_VALUES);
+    product.try_into().expect("reduced to 9 coefficients")
+}
+
+/// A single line of comma separated timers, each from 0 to 8.
+pub fn parse_input(raw_input: &str) -> Result<School, ParseError> {
+    let timers = comma_separated::<u8>(raw_input)?;
+    if let Some(timer) = timers.iter().find(|&&timer| timer as usize >= TIMER_VALUES) {
+        let (line, text) = numbered_lines(raw_input)
+            .next()
+            .expect("comma_separated found a line");
+        return Err(ParseError::new(
+            line,
+            text,
+            format!("timers go from 0 to {}, not {}", NEWBORN_TIMER, timer),
+        ));
+    }
+    Ok(School::from_timers(&timers))
+}
+
+pub fn part_one(school: &School) -> u64 {
+    school.after(80).expect("80 days fits in a u64").total()
+}
+
+pub fn part_two(school: &School) -> u64 {
+    school.after(256).expect("256 days fits in a u64").total()
+}
+
+pub struct DaySix;
+
+impl Solution for DaySix {
+    const TITLE: &'static str = "Day 6: Lanternfish";
+    const PART_ONE_QUESTION: &'static str = "How many lanternfish would there be after 80 days?";
+    const PART_TWO_QUESTION: &'static str = "How many lanternfish would there be after 256 days?";
+
+    type Input = School;
+    type PartOne = u64;
+    type PartTwo = u64;
+
+    fn parse(raw_input: &str) -> Result<School> {
+        Ok(parse_input(raw_input)?)
+    }
+
+    fn part_one(school: &School) -> Result<u64> {
+        Ok(part_one(school))
+    }
+
+    fn part_two(school: &School) -> Result<u64> {
+        Ok(part_two(school))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use num_bigint::BigUint;
+
+    use crate::{parse_input, part_one, part_two, School};
+
+    //the example from the puzzle description
+    const EXAMPLE: &str = include_str!("../example");
+
+    #[test]
+    fn test_example() {
+        let school = parse_input(EXAMPLE).expect("failed to parse example");
+        assert_eq!(school.total(), 5);
+        assert_eq!(school.after(18).unwrap().total(), 26);
+        assert_eq!(part_one(&school), 5934);
+        assert_eq!(part_two(&school), 26984457539);
+    }
+
+    #[test]
+    fn test_big_totals_match_stepping() {
+        let school = parse_input(EXAMPLE).unwrap();
+        for days in [0, 1, 18, 80, 256] {
+            assert_eq!(
+                school.total_after(days),
+                BigUint::from(school.after(days as usize).unwrap().total())
+            );
+        }
+
+        //past where u64 overflows, step a big histogram by hand
+        assert_eq!(school.after(1000), None);
+        let mut counts = school.counts().map(BigUint::from);
+        for _ in 0..1000 {
+            counts.rotate_left(1);
+            counts[6] += counts[8].clone();
+        }
+        assert_eq!(school.total_after(1000), counts.iter().sum::<BigUint>());
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        assert_eq!(parse_input("\n3,4,9\n").unwrap_err().line, 2);
+        assert_eq!(parse_input("3,4,x").unwrap_err().line, 1);
+        assert_eq!(parse_input("").unwrap_err().line, 1);
+        assert_eq!(
+            parse_input("0,8,8").unwrap(),
+            School::from_timers(&[8, 0, 8])
+        );
+    }
+}
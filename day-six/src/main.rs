@@ -0,0 +1,24 @@
+use anyhow::Result;
+use clap::Parser;
+use common::solution::{self, Solution};
+use day_six::{parse_input, DaySix};
+
+#[derive(Parser, Debug)]
+#[command(about = DaySix::TITLE)]
+struct Args {
+    /// Instead of answering the puzzle, print how many fish there are after this many days
+    #[arg(long)]
+    days: Option<u64>,
+}
+
+fn main() -> Result<()> {
+    let args = Args::parse();
+    let days = match args.days {
+        Some(days) => days,
+        None => return solution::run::<DaySix>(),
+    };
+
+    let school = parse_input(&common::read_input()?)?;
+    println!("{}", school.total_after(days));
+    Ok(())
+}
@@ -0,0 +1,262 @@
+//! Checking that brackets are balanced, for whichever pairs of opening and closing characters a
+//! [`BracketTable`] lists.
+
+use std::str::FromStr;
+
+use anyhow::{bail, Context, Error, Result};
+
+/// What a closer is worth, as the first illegal character of a corrupted line and as one step of
+/// the completion of an incomplete one.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub struct Scores {
+    pub syntax_error: u64,
+    pub completion: u64,
+}
+
+impl Scores {
+    /// The puzzle's scores, only `)`, `]`, `}` and `>` have them.
+    pub fn puzzle(close: char) -> Option<Scores> {
+        let (syntax_error, completion) = match close {
+            ')' => (3, 1),
+            ']' => (57, 2),
+            '}' => (1197, 3),
+            '>' => (25137, 4),
+            _ => return None,
+        };
+        Some(Scores {
+            syntax_error,
+            completion,
+        })
+    }
+}
+
+/// The pairs of characters that open and close a chunk, and what each closer scores.
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct BracketTable {
+    pairs: Vec<(char, char, Scores)>,
+}
+
+impl BracketTable {
+    /// A table of `(open, close)` pairs scored like the puzzle, erroring if any character is used
+    /// twice or a closer has no puzzle score.
+    pub fn new(pairs: &[(char, char)]) -> Result<Self> {
+        let pairs = pairs
+            .iter()
+            .map(|&(open, close)| {
+                let scores = Scores::puzzle(close)
+                    .with_context(|| format!("{:?} has no score, only )]}}> do", close))?;
+                Ok((open, close, scores))
+            })
+            .collect::<Result<Vec<(char, char, Scores)>>>()?;
+        BracketTable::with_scores(&pairs)
+    }
+
+    /// A table of `(open, close, scores)`, erroring if any character is used twice.
+    pub fn with_scores(pairs: &[(char, char, Scores)]) -> Result<Self> {
+        let mut seen = Vec::new();
+        for &(open, close, _) in pairs {
+            for c in [open, close] {
+                if seen.contains(&c) {
+                    bail!("{:?} is in more than one bracket pair", c);
+                }
+                seen.push(c);
+            }
+        }
+        Ok(BracketTable {
+            pairs: pairs.to_vec(),
+        })
+    }
+
+    /// The four pairs the navigation subsystem uses, `()`, `[]`, `{}` and `<>`.
+    pub fn standard() -> Self {
+        BracketTable::new(&[('(', ')'), ('[', ']'), ('{', '}'), ('<', '>')])
+            .expect("the standard pairs are distinct")
+    }
+
+    pub fn pairs(&self) -> impl Iterator<Item = (char, char)> + '_ {
+        self.pairs.iter().map(|&(open, close, _)| (open, close))
+    }
+
+    /// The character that closes `open`, if it opens anything.
+    pub fn closer(&self, open: char) -> Option<char> {
+        self.pairs
+            .iter()
+            .find(|&&(o, _, _)| o == open)
+            .map(|&(_, close, _)| close)
+    }
+
+    pub fn is_closer(&self, c: char) -> bool {
+        self.scores(c).is_some()
+    }
+
+    /// What `close` scores, if it closes anything.
+    pub fn scores(&self, close: char) -> Option<Scores> {
+        self.pairs
+            .iter()
+            .find(|&&(_, c, _)| c == close)
+            .map(|&(_, _, scores)| scores)
+    }
+
+    /// Times 5 plus the closer's worth, for every closer of the completion in turn. None for
+    /// anything that isn't a closer, or a completion too long for the score to fit.
+    pub fn completion_score(&self, completion: &str) -> Option<u64> {
+        completion.chars().try_fold(0u64, |score, close| {
+            score
+                .checked_mul(5)?
+                .checked_add(self.scores(close)?.completion)
+        })
+    }
+
+    /// Walks the line keeping a stack of the closers still owed. Characters that aren't in the
+    /// table are skipped.
+    pub fn check(&self, line: &str) -> Status {
+        let mut owed = Vec::new();
+        for (position, c) in line.chars().enumerate() {
+            if let Some(close) = self.closer(c) {
+                owed.push(close);
+            } else if self.is_closer(c) {
+                let expected = owed.pop();
+                if expected != Some(c) {
+                    return Status::Corrupted {
+                        position,
+                        expected,
+                        found: c,
+                    };
+                }
+            }
+        }
+        if owed.is_empty() {
+            Status::Valid
+        } else {
+            Status::Incomplete {
+                completion: owed.iter().rev().collect(),
+            }
+        }
+    }
+}
+
+/// Pairs written one after another with nothing between them, e.g. `()[]{}<>`. A pair whose
+/// closer the puzzle doesn't score needs its scores right after it, as `=syntax_error/completion`,
+/// e.g. `«»=5/1“”=7/2`.
+impl FromStr for BracketTable {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        let mut chars = s.chars().peekable();
+        let mut pairs = Vec::new();
+        while let Some(open) = chars.next() {
+            let close = chars
+                .next()
+                .with_context(|| format!("{:?} has nothing to close it in {:?}", open, s))?;
+            let scores = if chars.next_if_eq(&'=').is_some() {
+                let mut text = String::new();
+                while let Some(c) = chars.next_if(|&c| c.is_ascii_digit() || c == '/') {
+                    text.push(c);
+                }
+                let (syntax_error, completion) = text.split_once('/').with_context(|| {
+                    format!(
+                        "expected scores as =syntax_error/completion after {:?}",
+                        close
+                    )
+                })?;
+                Scores {
+                    syntax_error: syntax_error.parse()?,
+                    completion: completion.parse()?,
+                }
+            } else {
+                Scores::puzzle(close).with_context(|| {
+                    format!(
+                        "{:?} has no score, give it one like {}{}=5/1",
+                        close, open, close
+                    )
+                })?
+            };
+            pairs.push((open, close, scores));
+        }
+        if pairs.is_empty() {
+            bail!("expected pairs of open and close characters, found {:?}", s);
+        }
+        BracketTable::with_scores(&pairs)
+    }
+}
+
+/// What's wrong with a line, if anything.
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum Status {
+    Valid,
+    /// The first closer that doesn't match, with its 0-based character position, and the closer
+    /// that should have been there (`None` if nothing was open).
+    Corrupted {
+        position: usize,
+        expected: Option<char>,
+        found: char,
+    },
+    /// Every closer matched but some chunks were left open, `completion` closes them.
+    Incomplete {
+        completion: String,
+    },
+}
+
+#[cfg(test)]
+mod test {
+    use crate::brackets::{BracketTable, Status};
+
+    #[test]
+    fn test_standard_table() {
+        let table = BracketTable::standard();
+        assert_eq!(table.check("([]){<>}"), Status::Valid);
+        assert_eq!(
+            table.check("(]"),
+            Status::Corrupted {
+                position: 1,
+                expected: Some(')'),
+                found: ']'
+            }
+        );
+        assert_eq!(
+            table.check("())"),
+            Status::Corrupted {
+                position: 2,
+                expected: None,
+                found: ')'
+            }
+        );
+        assert_eq!(
+            table.check("{(<"),
+            Status::Incomplete {
+                completion: ">)}".to_string()
+            }
+        );
+    }
+
+    #[test]
+    fn test_other_tables() {
+        //a lisp-ish table where everything else is ignored
+        let table = "()".parse::<BracketTable>().unwrap();
+        assert_eq!(table.check("(define (f x) [x])"), Status::Valid);
+
+        let table = "«»=5/1“”=7/2".parse::<BracketTable>().unwrap();
+        assert_eq!(
+            table.pairs().collect::<Vec<(char, char)>>(),
+            vec![('«', '»'), ('“', '”')]
+        );
+        assert_eq!(
+            table.check("«“a»"),
+            Status::Corrupted {
+                position: 3,
+                expected: Some('”'),
+                found: '»'
+            }
+        );
+        assert_eq!(table.scores('»').map(|scores| scores.syntax_error), Some(5));
+        assert_eq!(table.completion_score("”»"), Some(2 * 5 + 1));
+
+        //closers the puzzle doesn't score need scores of their own
+        assert!("«»".parse::<BracketTable>().is_err());
+        assert!("«»=5".parse::<BracketTable>().is_err());
+        assert!(BracketTable::new(&[('«', '»')]).is_err());
+        assert!("()(".parse::<BracketTable>().is_err());
+        assert!("()[(".parse::<BracketTable>().is_err());
+        assert!("".parse::<BracketTable>().is_err());
+    }
+}
@@ -0,0 +1,129 @@
+//! Day 10: Syntax Scoring, finding the corrupted and incomplete lines of the navigation subsystem.
+
+pub mod brackets;
+
+use anyhow::{bail, Context, Result};
+use common::parse::numbered_lines;
+use common::solution::Solution;
+
+pub use brackets::{BracketTable, Scores, Status};
+
+/// The non-blank lines, as they are.
+pub fn parse_input(raw_input: &str) -> Vec<String> {
+    numbered_lines(raw_input)
+        .map(|(_, line)| line.to_string())
+        .collect()
+}
+
+/// The total syntax error score of the corrupted lines.
+pub fn part_one(lines: &[String], table: &BracketTable) -> u64 {
+    lines
+        .iter()
+        .filter_map(|line| match table.check(line) {
+            Status::Corrupted { found, .. } => {
+                table.scores(found).map(|scores| scores.syntax_error)
+            }
+            _ => None,
+        })
+        .sum()
+}
+
+/// The middle completion score of the incomplete lines, of which there's always an odd number.
+/// Errors if there are none, or if any line's score is too big to fit.
+pub fn part_two(lines: &[String], table: &BracketTable) -> Result<u64> {
+    let mut scores = Vec::new();
+    for line in lines {
+        if let Status::Incomplete { completion } = table.check(line) {
+            match table.completion_score(&completion) {
+                Some(score) => scores.push(score),
+                None => bail!("the completion {:?} scores too high to count", completion),
+            }
+        }
+    }
+    if scores.is_empty() {
+        bail!("no incomplete lines to score");
+    }
+    let middle = scores.len() / 2;
+    Ok(*scores.select_nth_unstable(middle).1)
+}
+
+pub struct DayTen;
+
+impl Solution for DayTen {
+    const TITLE: &'static str = "Day 10: Syntax Scoring";
+    const PART_ONE_QUESTION: &'static str =
+        "What is the total syntax error score for those errors?";
+    const PART_TWO_QUESTION: &'static str = "What is the middle score?";
+
+    type Input = Vec<String>;
+    type PartOne = u64;
+    type PartTwo = u64;
+
+    fn parse(raw_input: &str) -> Result<Vec<String>> {
+        Ok(parse_input(raw_input))
+    }
+
+    fn part_one(lines: &Vec<String>) -> Result<u64> {
+        Ok(part_one(lines, &BracketTable::standard()))
+    }
+
+    fn part_two(lines: &Vec<String>) -> Result<u64> {
+        part_two(lines, &BracketTable::standard()).context("couldn't find the middle score")
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use common::solution::Solution;
+
+    use crate::{parse_input, part_one, part_two, BracketTable, DayTen, Status};
+
+    //the example from the puzzle description
+    const EXAMPLE: &str = include_str!("../example");
+
+    #[test]
+    fn test_example() {
+        let lines = parse_input(EXAMPLE);
+        let table = BracketTable::standard();
+        assert_eq!(
+            table.check(&lines[2]),
+            Status::Corrupted {
+                position: 12,
+                expected: Some(']'),
+                found: '}'
+            }
+        );
+        assert_eq!(
+            table.check(&lines[0]),
+            Status::Incomplete {
+                completion: "}}]])})]".to_string()
+            }
+        );
+        assert_eq!(table.completion_score("}}]])})]"), Some(288957));
+        assert_eq!(part_one(&lines, &table), 26397);
+        assert_eq!(part_two(&lines, &table).unwrap(), 288957);
+    }
+
+    #[test]
+    fn test_long_completion() {
+        let table = BracketTable::standard();
+        assert_eq!(
+            table.completion_score(&">".repeat(27)),
+            Some(5u64.pow(27) - 1)
+        );
+        assert_eq!(table.completion_score(&">".repeat(28)), None);
+
+        //the long line has the middle score, so it can't just be left out
+        let lines = ["(", "((", &"<".repeat(30)].map(String::from);
+        assert!(part_two(&lines, &table).is_err());
+        assert!(DayTen::part_two(&lines.to_vec()).is_err());
+    }
+
+    #[test]
+    fn test_other_table() {
+        let table = "«»=5/1".parse::<BracketTable>().unwrap();
+        let lines = ["«»»", "««"].map(String::from);
+        assert_eq!(part_one(&lines, &table), 5);
+        assert_eq!(part_two(&lines, &table).unwrap(), 6);
+    }
+}
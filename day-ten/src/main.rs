@@ -0,0 +1,76 @@
+use anyhow::Result;
+use clap::Parser;
+use common::parse::numbered_lines;
+use common::solution::Solution;
+use day_ten::{parse_input, part_one, part_two, BracketTable, DayTen, Status};
+
+#[derive(Parser, Debug)]
+#[command(about = DayTen::TITLE)]
+struct Args {
+    /// The bracket pairs, open then close, one pair after another. Closers other than )]}> need
+    /// their scores after them, e.g. `«»=5/1`
+    #[arg(long, default_value = "()[]{}<>")]
+    pairs: BracketTable,
+
+    /// Print what's wrong with every line that isn't valid
+    #[arg(long)]
+    report: bool,
+}
+
+fn main() -> Result<()> {
+    let args = Args::parse();
+
+    println!("Reading input...");
+    let raw_input = common::read_input()?;
+    println!("Parsing input...");
+    let lines = parse_input(&raw_input);
+
+    if args.report {
+        println!("\nLines that aren't valid:\n");
+        for (line_number, line) in numbered_lines(&raw_input) {
+            match args.pairs.check(line) {
+                Status::Valid => {}
+                Status::Corrupted {
+                    position,
+                    expected: Some(expected),
+                    found,
+                } => println!(
+                    "{:>4}: corrupted, expected {:?} but found {:?} at {}",
+                    line_number,
+                    expected,
+                    found,
+                    position + 1
+                ),
+                Status::Corrupted {
+                    position,
+                    expected: None,
+                    found,
+                } => println!(
+                    "{:>4}: corrupted, {:?} at {} closes nothing",
+                    line_number,
+                    found,
+                    position + 1
+                ),
+                Status::Incomplete { completion } => {
+                    println!(
+                        "{:>4}: incomplete, complete with {}",
+                        line_number, completion
+                    )
+                }
+            }
+        }
+        println!();
+    }
+
+    println!("Part One:\n");
+    let answer = part_one(&lines, &args.pairs);
+    println!("Q: {}", DayTen::PART_ONE_QUESTION);
+    println!("A: {:?}", answer);
+
+    println!("\n\nPart Two:\n");
+    let answer = part_two(&lines, &args.pairs)?;
+    println!("Q: {}", DayTen::PART_TWO_QUESTION);
+    println!("A: {:?}", answer);
+
+    Ok(())
+}
@@ -0,0 +1,134 @@
+//! Day 13: Transparent Origami, folding the manual's paper to find the activation code.
+
+pub mod ocr;
+pub mod paper;
+
+use anyhow::{bail, Result};
+use common::parse::{blocks, ParseError};
+use common::solution::Solution;
+
+pub use ocr::read_letters;
+pub use paper::{Dot, Fold, Paper};
+
+/// The paper as it comes, and the folds to make in order.
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct Manual {
+    pub paper: Paper,
+    pub folds: Vec<Fold>,
+}
+
+impl Manual {
+    /// The paper after the first `folds` folds.
+    pub fn fold(&self, folds: usize) -> Result<Paper> {
+        self.folds
+            .iter()
+            .take(folds)
+            .try_fold(self.paper.clone(), |paper, &fold| paper.fold(fold))
+    }
+
+    pub fn fold_all(&self) -> Result<Paper> {
+        self.fold(self.folds.len())
+    }
+}
+
+/// A block of `x,y` dots, then a block of `fold along` instructions.
+pub fn parse_input(raw_input: &str) -> Result<Manual> {
+    let blocks = blocks(raw_input);
+    let [dots, folds] = blocks.as_slice() else {
+        bail!(
+            "expected a block of dots and a block of folds, found {} blocks",
+            blocks.len()
+        );
+    };
+    let dots = dots
+        .numbered_lines()
+        .map(|(line, text)| {
+            text.trim()
+                .parse()
+                .map_err(|e| ParseError::new(line, text, e))
+        })
+        .collect::<Result<Vec<Dot>, ParseError>>()?;
+    let folds = folds
+        .numbered_lines()
+        .map(|(line, text)| {
+            text.trim()
+                .parse()
+                .map_err(|e| ParseError::new(line, text, e))
+        })
+        .collect::<Result<Vec<Fold>, ParseError>>()?;
+    Ok(Manual {
+        paper: Paper::new(dots),
+        folds,
+    })
+}
+
+/// The dots left after just the first fold.
+pub fn part_one(manual: &Manual) -> Result<usize> {
+    Ok(manual.fold(1)?.len())
+}
+
+/// The letters the paper shows after every fold.
+pub fn part_two(manual: &Manual) -> Result<String> {
+    read_letters(&manual.fold_all()?)
+}
+
+pub struct DayThirteen;
+
+impl Solution for DayThirteen {
+    const TITLE: &'static str = "Day 13: Transparent Origami";
+    const PART_ONE_QUESTION: &'static str =
+        "How many dots are visible after completing just the first fold instruction on your transparent paper?";
+    const PART_TWO_QUESTION: &'static str =
+        "What code do you use to activate the infrared thermal imaging camera system?";
+
+    type Input = Manual;
+    type PartOne = usize;
+    type PartTwo = String;
+
+    fn parse(raw_input: &str) -> Result<Manual> {
+        parse_input(raw_input)
+    }
+
+    fn part_one(manual: &Manual) -> Result<usize> {
+        part_one(manual)
+    }
+
+    fn part_two(manual: &Manual) -> Result<String> {
+        part_two(manual)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use common::solution::Solution;
+
+    use crate::{parse_input, part_one, part_two, DayThirteen, Fold};
+
+    //the example from the puzzle description
+    const EXAMPLE: &str = include_str!("../example");
+
+    #[test]
+    fn test_example() {
+        let manual = parse_input(EXAMPLE).expect("failed to parse example");
+        assert_eq!(manual.folds, vec![Fold::Up(7), Fold::Left(5)]);
+        assert_eq!(part_one(&manual).unwrap(), 17);
+        let folded = manual.fold_all().unwrap();
+        assert_eq!(folded.len(), 16);
+        assert_eq!(folded.to_string(), "#####\n#...#\n#...#\n#...#\n#####\n");
+        //a square, not a letter
+        assert!(part_two(&manual).is_err());
+        //the runner gets the error, with the paper to read by eye
+        let error = DayThirteen::part_two(&manual).unwrap_err();
+        assert!(error.to_string().contains("#####\n#...#\n"));
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        assert!(parse_input("1,2\n3,4").is_err());
+        let error = parse_input("1,2\n3;4\n\nfold along x=1").unwrap_err();
+        assert_eq!(
+            error.downcast::<common::parse::ParseError>().unwrap().line,
+            2
+        );
+    }
+}
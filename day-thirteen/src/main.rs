@@ -0,0 +1,28 @@
+use anyhow::Result;
+use clap::Parser;
+use common::solution::{self, Solution};
+use day_thirteen::{parse_input, DayThirteen};
+
+#[derive(Parser, Debug)]
+#[command(about = DayThirteen::TITLE)]
+struct Args {
+    /// Print the paper after the first N folds, or after all of them, instead of solving
+    #[arg(long, value_name = "N", num_args = 0..=1, default_missing_value = "all")]
+    render: Option<String>,
+}
+
+fn main() -> Result<()> {
+    let args = Args::parse();
+    let Some(folds) = args.render else {
+        return solution::run::<DayThirteen>();
+    };
+
+    let raw_input = common::read_input()?;
+    let manual = parse_input(&raw_input)?;
+    let folds = match folds.as_str() {
+        "all" => manual.folds.len(),
+        n => n.parse()?,
+    };
+    print!("{}", manual.fold(folds)?);
+    Ok(())
+}
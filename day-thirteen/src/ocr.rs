@@ -0,0 +1,149 @@
+//! Reading the capital letters the folded paper spells out.
+//!
+//! Every puzzle answer drawn in dots uses the same 4 wide, 6 tall font, with a blank column
+//! between letters. Only the letters that turn up in puzzle answers are known, and not Y, which is
+//! the one letter drawn 5 wide.
+
+use anyhow::{bail, Result};
+
+use crate::paper::{Dot, Paper};
+
+pub const GLYPH_WIDTH: usize = 4;
+pub const GLYPH_HEIGHT: usize = 6;
+
+/// Each letter's rows, top to bottom.
+const GLYPHS: [(char, [&str; GLYPH_HEIGHT]); 17] = [
+    ('A', [".##.", "#..#", "#..#", "####", "#..#", "#..#"]),
+    ('B', ["###.", "#..#", "###.", "#..#", "#..#", "###."]),
+    ('C', [".##.", "#..#", "#...", "#...", "#..#", ".##."]),
+    ('E', ["####", "#...", "###.", "#...", "#...", "####"]),
+    ('F', ["####", "#...", "###.", "#...", "#...", "#..."]),
+    ('G', [".##.", "#..#", "#...", "#.##", "#..#", ".###"]),
+    ('H', ["#..#", "#..#", "####", "#..#", "#..#", "#..#"]),
+    ('I', [".###", "..#.", "..#.", "..#.", "..#.", ".###"]),
+    ('J', ["..##", "...#", "...#", "...#", "#..#", ".##."]),
+    ('K', ["#..#", "#.#.", "##..", "#.#.", "#.#.", "#..#"]),
+    ('L', ["#...", "#...", "#...", "#...", "#...", "####"]),
+    ('O', [".##.", "#..#", "#..#", "#..#", "#..#", ".##."]),
+    ('P', ["###.", "#..#", "#..#", "###.", "#...", "#..."]),
+    ('R', ["###.", "#..#", "#..#", "###.", "#.#.", "#..#"]),
+    ('S', [".###", "#...", "#...", ".##.", "...#", "###."]),
+    ('U', ["#..#", "#..#", "#..#", "#..#", "#..#", ".##."]),
+    ('Z', ["####", "...#", "..#.", ".#..", "#...", "####"]),
+];
+
+/// The letter drawn with its top left corner at `left`, 0, if it's one we know.
+fn letter_at(paper: &Paper, left: usize) -> Option<char> {
+    GLYPHS
+        .iter()
+        .find(|(_, rows)| {
+            rows.iter().enumerate().all(|(y, row)| {
+                row.chars()
+                    .enumerate()
+                    .all(|(x, c)| (c == '#') == paper.contains(Dot { x: left + x, y }))
+            })
+        })
+        .map(|&(letter, _)| letter)
+}
+
+/// The letters spelled out across the paper, left to right.
+pub fn read_letters(paper: &Paper) -> Result<String> {
+    let (width, height) = paper.size();
+    if paper.is_empty() {
+        bail!("there are no dots to read");
+    }
+    if height > GLYPH_HEIGHT {
+        bail!(
+            "letters are {} dots tall, the paper has dots {} down",
+            GLYPH_HEIGHT,
+            height
+        );
+    }
+    let letters = width.div_ceil(GLYPH_WIDTH + 1);
+    (0..letters)
+        .map(|index| {
+            let left = index * (GLYPH_WIDTH + 1);
+            let gap = Dot {
+                x: left + GLYPH_WIDTH,
+                y: 0,
+            };
+            if (0..GLYPH_HEIGHT).any(|y| paper.contains(Dot { y, ..gap })) {
+                bail!(
+                    "expected a blank column after letter {}\n{}",
+                    index + 1,
+                    paper
+                );
+            }
+            match letter_at(paper, left) {
+                Some(letter) => Ok(letter),
+                None => bail!(
+                    "letter {} isn't one of the known glyphs\n{}",
+                    index + 1,
+                    paper
+                ),
+            }
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod test {
+    use crate::ocr::{read_letters, GLYPHS};
+    use crate::paper::{Dot, Paper};
+
+    fn paper(art: &[&str]) -> Paper {
+        Paper::new(art.iter().enumerate().flat_map(|(y, row)| {
+            row.chars()
+                .enumerate()
+                .filter(|&(_, c)| c == '#')
+                .map(move |(x, _)| Dot { x, y })
+        }))
+    }
+
+    #[test]
+    fn test_every_glyph() {
+        for (letter, rows) in GLYPHS {
+            assert_eq!(read_letters(&paper(&rows)).unwrap(), letter.to_string());
+        }
+    }
+
+    #[test]
+    fn test_reading_a_word() {
+        let word = paper(&[
+            "#..#.####.#....#.....##.",
+            "#..#.#....#....#....#..#",
+            "####.###..#....#....#..#",
+            "#..#.#....#....#....#..#",
+            "#..#.#....#....#....#..#",
+            "#..#.####.####.####..##.",
+        ]);
+        assert_eq!(read_letters(&word).unwrap(), "HELLO");
+    }
+
+    #[test]
+    fn test_reading_an_answer() {
+        //a real puzzle answer as the folded paper draws it, out to the blank column after it
+        let answer = paper(&[
+            "###..####.####.#..#.###...##..####.###..",
+            "#..#....#.#....#..#.#..#.#..#.#....#..#.",
+            "#..#...#..###..####.#..#.#..#.###..#..#.",
+            "###...#...#....#..#.###..####.#....###..",
+            "#....#....#....#..#.#.#..#..#.#....#.#..",
+            "#....####.####.#..#.#..#.#..#.####.#..#.",
+        ]);
+        assert_eq!(read_letters(&answer).unwrap(), "PZEHRAER");
+    }
+
+    #[test]
+    fn test_unreadable() {
+        assert!(read_letters(&Paper::new([])).is_err());
+        //the example's square isn't a letter
+        let square = paper(&["#####", "#...#", "#...#", "#...#", "#####"]);
+        assert!(read_letters(&square).is_err());
+        //Y is 5 wide, so it runs into where the gap should be
+        let y = paper(&["#...#", "#...#", ".#.#.", "..#..", "..#..", "..#.."]);
+        assert!(read_letters(&y).is_err());
+        let too_tall = paper(&["#", "#", "#", "#", "#", "#", "#"]);
+        assert!(read_letters(&too_tall).is_err());
+    }
+}
@@ -0,0 +1,166 @@
+//! The transparent paper, its dots and folding it up.
+
+use std::collections::BTreeSet;
+use std::fmt;
+use std::str::FromStr;
+
+use anyhow::{bail, Context, Error, Result};
+
+#[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Clone, Copy, Hash)]
+pub struct Dot {
+    pub x: usize,
+    pub y: usize,
+}
+
+/// `x,y`
+impl FromStr for Dot {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        let (x, y) = s.split_once(',').context("expected a dot as x,y")?;
+        Ok(Dot {
+            x: x.trim().parse()?,
+            y: y.trim().parse()?,
+        })
+    }
+}
+
+/// Folding along a vertical line `x = n` (left) or a horizontal line `y = n` (up).
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum Fold {
+    Left(usize),
+    Up(usize),
+}
+
+impl Fold {
+    /// Where a dot ends up, or an error if it would fold past the far edge.
+    pub fn apply(self, dot: Dot) -> Result<Dot> {
+        let mirror = |position: usize, line: usize| {
+            if position <= line {
+                return Ok(position);
+            }
+            match (2 * line).checked_sub(position) {
+                Some(folded) => Ok(folded),
+                None => bail!(
+                    "{} folds past the edge of the paper along {}",
+                    position,
+                    line
+                ),
+            }
+        };
+        Ok(match self {
+            Fold::Left(line) => Dot {
+                x: mirror(dot.x, line)?,
+                y: dot.y,
+            },
+            Fold::Up(line) => Dot {
+                x: dot.x,
+                y: mirror(dot.y, line)?,
+            },
+        })
+    }
+}
+
+/// `fold along x=5` or `fold along y=7`
+impl FromStr for Fold {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        let (axis, line) = s
+            .strip_prefix("fold along ")
+            .and_then(|fold| fold.split_once('='))
+            .context("expected fold along x=n or y=n")?;
+        let line = line.trim().parse()?;
+        match axis {
+            "x" => Ok(Fold::Left(line)),
+            "y" => Ok(Fold::Up(line)),
+            _ => bail!("folds go along x or y, found {:?}", axis),
+        }
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct Paper {
+    dots: BTreeSet<Dot>,
+}
+
+impl Paper {
+    pub fn new(dots: impl IntoIterator<Item = Dot>) -> Self {
+        Paper {
+            dots: dots.into_iter().collect(),
+        }
+    }
+
+    /// Overlapping dots only count once.
+    pub fn len(&self) -> usize {
+        self.dots.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.dots.is_empty()
+    }
+
+    pub fn contains(&self, dot: Dot) -> bool {
+        self.dots.contains(&dot)
+    }
+
+    /// One past the furthest dot each way, `(width, height)`.
+    pub fn size(&self) -> (usize, usize) {
+        let width = self.dots.iter().map(|dot| dot.x + 1).max().unwrap_or(0);
+        let height = self.dots.iter().map(|dot| dot.y + 1).max().unwrap_or(0);
+        (width, height)
+    }
+
+    pub fn fold(&self, fold: Fold) -> Result<Paper> {
+        Ok(Paper {
+            dots: self
+                .dots
+                .iter()
+                .map(|&dot| fold.apply(dot))
+                .collect::<Result<BTreeSet<Dot>>>()?,
+        })
+    }
+}
+
+/// `#` for dots and `.` for the rest, out to the furthest dot.
+impl fmt::Display for Paper {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let (width, height) = self.size();
+        for y in 0..height {
+            let row = (0..width)
+                .map(|x| {
+                    if self.contains(Dot { x, y }) {
+                        '#'
+                    } else {
+                        '.'
+                    }
+                })
+                .collect::<String>();
+            writeln!(f, "{}", row)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::paper::{Dot, Fold, Paper};
+
+    #[test]
+    fn test_parsing() {
+        assert_eq!("6,10".parse::<Dot>().unwrap(), Dot { x: 6, y: 10 });
+        assert_eq!("fold along y=7".parse::<Fold>().unwrap(), Fold::Up(7));
+        assert_eq!("fold along x=5".parse::<Fold>().unwrap(), Fold::Left(5));
+        assert!("fold along z=5".parse::<Fold>().is_err());
+        assert!("fold x=5".parse::<Fold>().is_err());
+    }
+
+    #[test]
+    fn test_folding() {
+        let paper = Paper::new([Dot { x: 0, y: 0 }, Dot { x: 4, y: 1 }, Dot { x: 3, y: 1 }]);
+        let folded = paper.fold(Fold::Left(2)).unwrap();
+        assert_eq!(folded.len(), 3);
+        assert_eq!(folded.to_string(), "#.\n##\n");
+        assert!(paper.fold(Fold::Left(1)).is_err());
+    }
+}
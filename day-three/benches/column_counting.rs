@@ -0,0 +1,69 @@
+//! Compares the original `chars().nth(i)` column counting against the byte-wise scalar
+//! fallback and the bit-packed `count_ones` path on a million-line report.
+//!
+//! Run with `cargo bench -p day-three`.
+
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+
+use day_three::{column_counts_scalar, DiagnosticReport};
+
+const LINES: usize = 1_000_000;
+const WIDTH: usize = 12;
+
+fn generate_report() -> Vec<String> {
+    //xorshift, so the report is the same on every run without pulling in an rng crate
+    let mut state = 0x2545_f491_4f6c_dd1du64;
+    (0..LINES)
+        .map(|_| {
+            state ^= state << 13;
+            state ^= state >> 7;
+            state ^= state << 17;
+            format!("{:0width$b}", state >> (64 - WIDTH), width = WIDTH)
+        })
+        .collect()
+}
+
+//the counting loop `part_one` used before the report was bit-packed
+fn column_counts_chars(input: &[&str]) -> (Vec<usize>, Vec<usize>) {
+    let number_length = input[0].len();
+    let (mut ones, mut zeros) = (vec![0; number_length], vec![0; number_length]);
+    for s in input {
+        for i in 0..number_length {
+            match s.chars().nth(i).unwrap() {
+                '0' => zeros[i] += 1,
+                '1' => ones[i] += 1,
+                c => panic!("unexpected character {}", c),
+            }
+        }
+    }
+    (ones, zeros)
+}
+
+fn bench_column_counting(c: &mut Criterion) {
+    let owned = generate_report();
+    let lines = owned.iter().map(|s| s.as_str()).collect::<Vec<&str>>();
+    let packed = DiagnosticReport::from_lines(&lines).expect("failed to pack report");
+
+    let mut group = c.benchmark_group("column counting, 1M lines");
+    group.sample_size(10);
+    group.bench_function("chars().nth", |b| {
+        b.iter(|| column_counts_chars(black_box(&lines)))
+    });
+    group.bench_function("scalar bytes", |b| {
+        b.iter(|| column_counts_scalar(black_box(&lines)).unwrap())
+    });
+    group.bench_function("pack + count_ones", |b| {
+        b.iter(|| {
+            DiagnosticReport::from_lines(black_box(&lines))
+                .unwrap()
+                .column_counts()
+        })
+    });
+    group.bench_function("count_ones (pre-packed)", |b| {
+        b.iter(|| black_box(&packed).column_counts())
+    });
+    group.finish();
+}
+
+criterion_group!(benches, bench_column_counting);
+criterion_main!(benches);
@@ -0,0 +1,179 @@
+//! Day 3: Binary Diagnostic, reading the submarine's diagnostic report.
+
+pub mod report;
+
+use anyhow::{bail, Context, Result};
+use common::parse::{bit_lines, ParseError};
+use common::solution::Solution;
+pub use report::{
+    column_count_parallel, column_count_scalar, column_counts_scalar, ColumnCount, DiagnosticReport,
+};
+
+pub struct DayThree;
+
+impl Solution for DayThree {
+    const TITLE: &'static str = "Day 3: Binary Diagnostic";
+    const PART_ONE_QUESTION: &'static str = "What is the power consumption of the submarine?";
+    const PART_TWO_QUESTION: &'static str = "What is the life support rating of the submarine?";
+
+    type Input = Vec<String>;
+    type PartOne = u64;
+    type PartTwo = u64;
+
+    fn parse(raw_input: &str) -> Result<Vec<String>> {
+        Ok(parse_input(raw_input)?
+            .into_iter()
+            .map(str::to_string)
+            .collect())
+    }
+
+    fn part_one(lines: &Vec<String>) -> Result<u64> {
+        part_one(&lines.iter().map(String::as_str).collect::<Vec<&str>>())
+    }
+
+    fn part_two(lines: &Vec<String>) -> Result<u64> {
+        part_two(&lines.iter().map(String::as_str).collect::<Vec<&str>>())
+    }
+}
+
+/// One binary number per line, every line the same width.
+pub fn parse_input(raw_input: &str) -> Result<Vec<&str>, ParseError> {
+    bit_lines(raw_input)
+}
+
+/// Power consumption, the gamma rate (most common bit of every column) times the epsilon rate
+/// (least common bit).
+pub fn part_one(input: &[&str]) -> Result<u64> {
+    let report = DiagnosticReport::from_lines(input)?;
+
+    let (mut gamma, mut epsilon) = (0u64, 0u64);
+    let counts = if cfg!(feature = "parallel") {
+        report.column_counts_parallel()
+    } else {
+        report.column_counts()
+    };
+
+    for count in counts {
+        gamma <<= 1;
+        epsilon <<= 1;
+
+        if count.ones > count.zeros {
+            gamma |= 1;
+        }
+
+        if count.ones < count.zeros {
+            epsilon |= 1;
+        }
+    }
+
+    Ok(gamma * epsilon)
+}
+
+/// Life support rating, the oxygen generator rating times the CO2 scrubber rating.
+pub fn part_two(input: &[&str]) -> Result<u64> {
+    let oxygen_generator_rating_as_string = recurse(None, input, BitCriteria::OxygenGenerator)?;
+    let co2_scrubber_rating_as_string = recurse(None, input, BitCriteria::Co2Scrubber)?;
+    let ogr = u64::from_str_radix(oxygen_generator_rating_as_string, 2)
+        .context("couldn't parse oxygen generator rating string into decimal")?;
+    let csr = u64::from_str_radix(co2_scrubber_rating_as_string, 2)
+        .context("couldn't parse co2 scrubber rating into decimal")?;
+    Ok(ogr * csr)
+}
+
+enum BitCriteria {
+    OxygenGenerator,
+    Co2Scrubber,
+}
+
+fn recurse<'input>(
+    position: Option<usize>,
+    input: &[&'input str],
+    bit_criteria: BitCriteria,
+) -> Result<&'input str> {
+    let position = position.unwrap_or(0);
+    let width = input.first().map_or(0, |line| line.len());
+    if position >= width {
+        bail!(
+            "ran out of bits with {} candidate ratings left",
+            input.len()
+        );
+    }
+    //only this column decides which candidates are kept, so it's the only one worth counting
+    let ColumnCount {
+        ones: num_ones,
+        zeros: num_zeros,
+    } = if cfg!(feature = "parallel") {
+        column_count_parallel(input, position)?
+    } else {
+        column_count_scalar(input, position)?
+    };
+
+    let required_character_at_position = match bit_criteria {
+        BitCriteria::OxygenGenerator => {
+            if num_ones >= num_zeros {
+                b'1'
+            } else {
+                b'0'
+            }
+        }
+        BitCriteria::Co2Scrubber => {
+            if num_zeros <= num_ones {
+                b'0'
+            } else {
+                b'1'
+            }
+        }
+    };
+
+    let input_filtered_for_required_character_at_position = input
+        .iter()
+        .filter(|s| s.as_bytes()[position] == required_character_at_position)
+        .copied()
+        .collect::<Vec<&str>>();
+
+    match input_filtered_for_required_character_at_position.len() {
+        1 => Ok(input_filtered_for_required_character_at_position[0]),
+        0 => bail!("we filtered down to zero matching lines, something is wrong"),
+        _ => recurse(
+            Some(position + 1),
+            &input_filtered_for_required_character_at_position,
+            bit_criteria,
+        ),
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use common::solution::Solution;
+
+    use crate::{part_one, part_two, DayThree};
+
+    #[test]
+    fn test_part_one() {
+        //using example from prompt
+        let input = vec![
+            "00100", "11110", "10110", "10111", "10101", "01111", "00111", "11100", "10000",
+            "11001", "00010", "01010",
+        ];
+        let result = part_one(&input).unwrap();
+        assert_eq!(result, 198);
+    }
+
+    #[test]
+    fn test_part_two() {
+        let input = vec![
+            "00100", "11110", "10110", "10111", "10101", "01111", "00111", "11100", "10000",
+            "11001", "00010", "01010",
+        ];
+        let result = part_two(&input).unwrap();
+        assert_eq!(result, 230);
+    }
+
+    #[test]
+    fn test_part_two_errors() {
+        //the duplicates can never be narrowed down to one rating
+        let duplicates = vec!["01".to_string(), "01".to_string()];
+        assert!(DayThree::part_two(&duplicates).is_err());
+        assert!(DayThree::part_two(&vec![]).is_err());
+    }
+}
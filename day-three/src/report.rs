@@ -0,0 +1,219 @@
+use std::ops::Range;
+
+use anyhow::{bail, Result};
+
+/// The number of ones and zeros seen in a single column of the report.
+#[derive(Debug, PartialEq, Clone, Copy, Default)]
+pub struct ColumnCount {
+    pub ones: usize,
+    pub zeros: usize,
+}
+
+/// A diagnostic report packed into transposed bit-slices.
+///
+/// Every column gets its own run of `u64` words where bit `r % 64` of word `r / 64` is the bit
+/// of row `r`, so counting the ones in a column is a `count_ones` per 64 rows instead of a
+/// character lookup per cell.
+#[derive(Debug, PartialEq, Clone)]
+pub struct DiagnosticReport {
+    width: usize,
+    len: usize,
+    columns: Vec<Vec<u64>>,
+}
+
+impl DiagnosticReport {
+    pub fn from_lines(lines: &[&str]) -> Result<Self> {
+        let width = lines.first().map_or(0, |line| line.len());
+        let words_per_column = lines.len().div_ceil(64);
+        let mut columns = vec![vec![0u64; words_per_column]; width];
+
+        for (row, line) in lines.iter().enumerate() {
+            if line.len() != width {
+                bail!(
+                    "line {} has {} bits but the first line has {}",
+                    row + 1,
+                    line.len(),
+                    width
+                );
+            }
+            for (column, byte) in line.bytes().enumerate() {
+                match byte {
+                    b'0' => {}
+                    b'1' => columns[column][row / 64] |= 1 << (row % 64),
+                    _ => bail!("unexpected character {:?} on line {}", byte as char, row + 1),
+                }
+            }
+        }
+
+        Ok(DiagnosticReport {
+            width,
+            len: lines.len(),
+            columns,
+        })
+    }
+
+    /// Counts the ones and zeros of every column using `count_ones` over the packed bit-slices.
+    pub fn column_counts(&self) -> Vec<ColumnCount> {
+        self.columns
+            .iter()
+            .map(|words| {
+                let ones = words.iter().map(|w| w.count_ones() as usize).sum::<usize>();
+                ColumnCount {
+                    ones,
+                    zeros: self.len - ones,
+                }
+            })
+            .collect()
+    }
+
+    /// Same counts as [`DiagnosticReport::column_counts`], but the rows are split into chunks
+    /// that are counted in parallel when the `parallel` feature is on.
+    pub fn column_counts_parallel(&self) -> Vec<ColumnCount> {
+        //how many u64 words (64 rows each) every chunk covers
+        const WORDS_PER_CHUNK: usize = 64;
+
+        let words_per_column = self.columns.first().map_or(0, |words| words.len());
+        let ones = common::parallel::map_reduce_ranges(
+            words_per_column,
+            WORDS_PER_CHUNK,
+            || vec![0usize; self.columns.len()],
+            |range| {
+                self.columns
+                    .iter()
+                    .map(|words| {
+                        words[range.clone()]
+                            .iter()
+                            .map(|w| w.count_ones() as usize)
+                            .sum()
+                    })
+                    .collect()
+            },
+            |mut a, b| {
+                a.iter_mut().zip(b).for_each(|(a, b)| *a += b);
+                a
+            },
+        );
+
+        ones.into_iter()
+            .map(|ones| ColumnCount {
+                ones,
+                zeros: self.len - ones,
+            })
+            .collect()
+    }
+}
+
+/// Portable fallback that counts straight from the text, one byte per cell, without packing.
+///
+/// Produces the same counts as [`DiagnosticReport::column_counts`].
+pub fn column_counts_scalar(lines: &[&str]) -> Result<Vec<ColumnCount>> {
+    let width = lines.first().map_or(0, |line| line.len());
+    let mut counts = vec![ColumnCount::default(); width];
+    for (row, line) in lines.iter().enumerate() {
+        if line.len() != width {
+            bail!(
+                "line {} has {} bits but the first line has {}",
+                row + 1,
+                line.len(),
+                width
+            );
+        }
+        for (count, byte) in counts.iter_mut().zip(line.bytes()) {
+            match byte {
+                b'0' => count.zeros += 1,
+                b'1' => count.ones += 1,
+                _ => bail!("unexpected character {:?} on line {}", byte as char, row + 1),
+            }
+        }
+    }
+    Ok(counts)
+}
+
+/// The count of just one column, straight from the text like [`column_counts_scalar`].
+pub fn column_count_scalar(lines: &[&str], column: usize) -> Result<ColumnCount> {
+    count_column_rows(lines, 0..lines.len(), column)
+}
+
+/// Same count as [`column_count_scalar`], but the rows are split into chunks that are counted in
+/// parallel when the `parallel` feature is on.
+pub fn column_count_parallel(lines: &[&str], column: usize) -> Result<ColumnCount> {
+    //how many lines every chunk covers
+    const LINES_PER_CHUNK: usize = 4096;
+
+    common::parallel::map_reduce_ranges(
+        lines.len(),
+        LINES_PER_CHUNK,
+        || Ok(ColumnCount::default()),
+        |rows| count_column_rows(lines, rows, column),
+        |a, b| {
+            let (a, b) = (a?, b?);
+            Ok(ColumnCount {
+                ones: a.ones + b.ones,
+                zeros: a.zeros + b.zeros,
+            })
+        },
+    )
+}
+
+fn count_column_rows(lines: &[&str], rows: Range<usize>, column: usize) -> Result<ColumnCount> {
+    let mut count = ColumnCount::default();
+    for row in rows {
+        match lines[row].as_bytes().get(column) {
+            Some(b'0') => count.zeros += 1,
+            Some(b'1') => count.ones += 1,
+            Some(&byte) => bail!("unexpected character {:?} on line {}", byte as char, row + 1),
+            None => bail!("line {} has no bit {}", row + 1, column + 1),
+        }
+    }
+    Ok(count)
+}
+
+#[cfg(test)]
+mod test {
+    use crate::report::{
+        column_count_parallel, column_count_scalar, column_counts_scalar, ColumnCount,
+        DiagnosticReport,
+    };
+
+    #[test]
+    fn test_column_counts() {
+        let input = ["101", "001", "111"];
+        let report = DiagnosticReport::from_lines(&input).expect("failed to pack report");
+        assert_eq!(
+            report.column_counts(),
+            vec![
+                ColumnCount { ones: 2, zeros: 1 },
+                ColumnCount { ones: 1, zeros: 2 },
+                ColumnCount { ones: 3, zeros: 0 },
+            ]
+        );
+    }
+
+    #[test]
+    fn test_popcount_matches_scalar_across_word_boundaries() {
+        //enough rows to spill over several u64 words, with an uneven tail
+        let lines = (0..1000u32)
+            .map(|n| format!("{:012b}", n.wrapping_mul(2654435761) >> 20))
+            .collect::<Vec<String>>();
+        let lines = lines.iter().map(|s| s.as_str()).collect::<Vec<&str>>();
+
+        let report = DiagnosticReport::from_lines(&lines).expect("failed to pack report");
+        let scalar = column_counts_scalar(&lines).expect("failed to count report");
+        assert_eq!(report.column_counts(), scalar);
+        assert_eq!(report.column_counts_parallel(), scalar);
+        for (column, &count) in scalar.iter().enumerate() {
+            assert_eq!(column_count_scalar(&lines, column).unwrap(), count);
+            assert_eq!(column_count_parallel(&lines, column).unwrap(), count);
+        }
+    }
+
+    #[test]
+    fn test_rejects_bad_input() {
+        assert!(DiagnosticReport::from_lines(&["101", "10"]).is_err());
+        assert!(DiagnosticReport::from_lines(&["101", "1x1"]).is_err());
+        assert!(column_counts_scalar(&["101", "10"]).is_err());
+        assert!(column_count_scalar(&["101", "10"], 2).is_err());
+        assert!(column_count_scalar(&["101", "1x1"], 1).is_err());
+        assert!(column_count_parallel(&["101", "1x1"], 1).is_err());
+    }
+}
@@ -0,0 +1,255 @@
+//! The cave system as a graph of interned caves, and the paths through it.
+
+use std::collections::HashMap;
+
+use anyhow::{bail, Context, Result};
+use clap::ValueEnum;
+use common::parse::{numbered_lines, ParseError};
+
+pub type CaveId = usize;
+
+/// Small caves get a bit each in the visited mask.
+pub const MAX_SMALL_CAVES: usize = u64::BITS as usize;
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct Cave {
+    pub name: String,
+    /// Lowercase names, the caves a path can only go through a limited number of times.
+    pub small: bool,
+}
+
+/// How often a path may go through a small cave.
+#[derive(Debug, PartialEq, Eq, Clone, Copy, ValueEnum)]
+pub enum VisitRule {
+    /// Every small cave at most once
+    SmallOnce,
+    /// One small cave, other than start and end, twice and the rest once
+    OneSmallTwice,
+}
+
+impl VisitRule {
+    fn allows_revisit(self) -> bool {
+        self == VisitRule::OneSmallTwice
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct CaveSystem {
+    caves: Vec<Cave>,
+    ids: HashMap<String, CaveId>,
+    links: Vec<Vec<CaveId>>,
+    /// The bit of every small cave in a visited mask, 0 for the big ones.
+    bits: Vec<u64>,
+    start: CaveId,
+    end: CaveId,
+}
+
+impl CaveSystem {
+    /// One `a-b` passage per line. There has to be a `start` and an `end`, and no two big caves
+    /// can be linked or paths could go back and forth between them forever.
+    pub fn parse(input: &str) -> Result<Self> {
+        let mut caves = Vec::new();
+        let mut ids = HashMap::new();
+        let mut links = Vec::<Vec<CaveId>>::new();
+        for (line, text) in numbered_lines(input) {
+            let (a, b) = text
+                .split_once('-')
+                .filter(|(a, b)| !a.is_empty() && !b.is_empty())
+                .ok_or_else(|| ParseError::new(line, text, "expected two caves joined by -"))?;
+            let mut intern = |name: &str| {
+                *ids.entry(name.to_string()).or_insert_with(|| {
+                    caves.push(Cave {
+                        name: name.to_string(),
+                        small: name.chars().all(|c| c.is_ascii_lowercase()),
+                    });
+                    links.push(Vec::new());
+                    caves.len() - 1
+                })
+            };
+            let (a, b) = (intern(a), intern(b));
+            if !caves[a].small && !caves[b].small {
+                return Err(ParseError::new(
+                    line,
+                    text,
+                    "two big caves next to each other make endless paths",
+                )
+                .into());
+            }
+            links[a].push(b);
+            links[b].push(a);
+        }
+
+        let start = *ids.get("start").context("there's no start cave")?;
+        let end = *ids.get("end").context("there's no end cave")?;
+        let small_caves = caves.iter().filter(|cave| cave.small).count();
+        if small_caves > MAX_SMALL_CAVES {
+            bail!(
+                "at most {} small caves fit in the visited mask, found {}",
+                MAX_SMALL_CAVES,
+                small_caves
+            );
+        }
+        let mut next_bit = 0;
+        let bits = caves
+            .iter()
+            .map(|cave| {
+                if !cave.small {
+                    return 0;
+                }
+                next_bit += 1;
+                1 << (next_bit - 1)
+            })
+            .collect();
+
+        Ok(CaveSystem {
+            caves,
+            ids,
+            links,
+            bits,
+            start,
+            end,
+        })
+    }
+
+    pub fn caves(&self) -> &[Cave] {
+        &self.caves
+    }
+
+    pub fn id(&self, name: &str) -> Option<CaveId> {
+        self.ids.get(name).copied()
+    }
+
+    pub fn links(&self, cave: CaveId) -> &[CaveId] {
+        &self.links[cave]
+    }
+
+    /// Whether a path that's been through the `visited` small caves can go into `cave`, and if so
+    /// whether it uses up the one revisit.
+    fn enter(&self, cave: CaveId, visited: u64, can_revisit: bool) -> Option<(u64, bool)> {
+        if cave == self.start {
+            return None;
+        }
+        let bit = self.bits[cave];
+        if visited & bit == 0 {
+            Some((visited | bit, can_revisit))
+        } else if can_revisit && cave != self.end {
+            Some((visited, false))
+        } else {
+            None
+        }
+    }
+
+    /// How many paths go from start to end, by a depth first search memoized on where it is,
+    /// which small caves it's been through and whether it can still revisit one.
+    pub fn count_paths(&self, rule: VisitRule) -> u64 {
+        let mut memo = HashMap::new();
+        self.count_from(
+            self.start,
+            self.bits[self.start],
+            rule.allows_revisit(),
+            &mut memo,
+        )
+    }
+
+    fn count_from(
+        &self,
+        cave: CaveId,
+        visited: u64,
+        can_revisit: bool,
+        memo: &mut HashMap<(CaveId, u64, bool), u64>,
+    ) -> u64 {
+        if cave == self.end {
+            return 1;
+        }
+        if let Some(&count) = memo.get(&(cave, visited, can_revisit)) {
+            return count;
+        }
+        let count = self.links[cave]
+            .iter()
+            .filter_map(|&next| {
+                let (visited, can_revisit) = self.enter(next, visited, can_revisit)?;
+                Some(self.count_from(next, visited, can_revisit, memo))
+            })
+            .sum();
+        memo.insert((cave, visited, can_revisit), count);
+        count
+    }
+
+    /// Every path from start to end, as cave ids. There can be a great many of them, so this is
+    /// for listing small systems, [`CaveSystem::count_paths`] is for counting.
+    pub fn paths(&self, rule: VisitRule) -> Vec<Vec<CaveId>> {
+        let mut paths = Vec::new();
+        let mut path = vec![self.start];
+        self.extend_paths(
+            &mut path,
+            self.bits[self.start],
+            rule.allows_revisit(),
+            &mut paths,
+        );
+        paths
+    }
+
+    fn extend_paths(
+        &self,
+        path: &mut Vec<CaveId>,
+        visited: u64,
+        can_revisit: bool,
+        paths: &mut Vec<Vec<CaveId>>,
+    ) {
+        let cave = *path.last().expect("paths start at start");
+        if cave == self.end {
+            paths.push(path.clone());
+            return;
+        }
+        for &next in &self.links[cave] {
+            if let Some((visited, can_revisit)) = self.enter(next, visited, can_revisit) {
+                path.push(next);
+                self.extend_paths(path, visited, can_revisit, paths);
+                path.pop();
+            }
+        }
+    }
+
+    /// `start,A,b,end`
+    pub fn path_names(&self, path: &[CaveId]) -> String {
+        path.iter()
+            .map(|&cave| self.caves[cave].name.as_str())
+            .collect::<Vec<&str>>()
+            .join(",")
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::caves::{CaveSystem, VisitRule};
+
+    #[test]
+    fn test_interning() {
+        let caves = CaveSystem::parse("start-A\nA-end\nA-b\nb-end").unwrap();
+        assert_eq!(caves.caves().len(), 4);
+        let a = caves.id("A").unwrap();
+        assert!(!caves.caves()[a].small);
+        assert!(caves.caves()[caves.id("b").unwrap()].small);
+        assert_eq!(caves.links(a).len(), 3);
+    }
+
+    #[test]
+    fn test_listing() {
+        let caves = CaveSystem::parse("start-A\nA-end\nA-b").unwrap();
+        let paths = caves
+            .paths(VisitRule::SmallOnce)
+            .iter()
+            .map(|path| caves.path_names(path))
+            .collect::<Vec<String>>();
+        assert_eq!(paths, vec!["start,A,end", "start,A,b,A,end"]);
+        assert_eq!(caves.paths(VisitRule::OneSmallTwice).len(), 3);
+        assert_eq!(caves.count_paths(VisitRule::OneSmallTwice), 3);
+    }
+
+    #[test]
+    fn test_bad_systems() {
+        assert!(CaveSystem::parse("start-A\nA-B\nB-end").is_err());
+        assert!(CaveSystem::parse("start-a").is_err());
+        assert!(CaveSystem::parse("start-a\na end").is_err());
+    }
+}
@@ -0,0 +1,80 @@
+//! Day 12: Passage Pathing, counting the ways through the cave system.
+
+pub mod caves;
+
+use anyhow::Result;
+use common::solution::Solution;
+
+pub use caves::{Cave, CaveId, CaveSystem, VisitRule};
+
+pub fn parse_input(raw_input: &str) -> Result<CaveSystem> {
+    CaveSystem::parse(raw_input)
+}
+
+/// Paths through every small cave at most once.
+pub fn part_one(caves: &CaveSystem) -> u64 {
+    caves.count_paths(VisitRule::SmallOnce)
+}
+
+/// Paths through one small cave twice and the rest at most once.
+pub fn part_two(caves: &CaveSystem) -> u64 {
+    caves.count_paths(VisitRule::OneSmallTwice)
+}
+
+pub struct DayTwelve;
+
+impl Solution for DayTwelve {
+    const TITLE: &'static str = "Day 12: Passage Pathing";
+    const PART_ONE_QUESTION: &'static str =
+        "How many paths through this cave system are there that visit small caves at most once?";
+    const PART_TWO_QUESTION: &'static str =
+        "Given these new rules, how many paths through this cave system are there?";
+
+    type Input = CaveSystem;
+    type PartOne = u64;
+    type PartTwo = u64;
+
+    fn parse(raw_input: &str) -> Result<CaveSystem> {
+        parse_input(raw_input)
+    }
+
+    fn part_one(caves: &CaveSystem) -> Result<u64> {
+        Ok(part_one(caves))
+    }
+
+    fn part_two(caves: &CaveSystem) -> Result<u64> {
+        Ok(part_two(caves))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{parse_input, part_one, part_two, VisitRule};
+
+    //the example from the puzzle description
+    const EXAMPLE: &str = include_str!("../example");
+
+    //its two bigger examples
+    const LARGER_EXAMPLE: &str =
+        "dc-end\nHN-start\nstart-kj\ndc-start\ndc-HN\nLN-dc\nHN-end\nkj-sa\nkj-HN\nkj-dc";
+    const EVEN_LARGER_EXAMPLE: &str = "fs-end\nhe-DX\nfs-he\nstart-DX\npj-DX\nend-zg\nzg-sl\n\
+        zg-pj\npj-he\nRW-he\nfs-DX\npj-RW\nzg-RW\nstart-pj\nhe-WI\nzg-he\npj-fs\nstart-RW";
+
+    #[test]
+    fn test_example() {
+        let caves = parse_input(EXAMPLE).expect("failed to parse example");
+        assert_eq!(part_one(&caves), 10);
+        assert_eq!(part_two(&caves), 36);
+        for rule in [VisitRule::SmallOnce, VisitRule::OneSmallTwice] {
+            assert_eq!(caves.paths(rule).len() as u64, caves.count_paths(rule));
+        }
+    }
+
+    #[test]
+    fn test_larger_examples() {
+        let caves = parse_input(LARGER_EXAMPLE).unwrap();
+        assert_eq!((part_one(&caves), part_two(&caves)), (19, 103));
+        let caves = parse_input(EVEN_LARGER_EXAMPLE).unwrap();
+        assert_eq!((part_one(&caves), part_two(&caves)), (226, 3509));
+    }
+}
@@ -0,0 +1,28 @@
+use anyhow::Result;
+use clap::Parser;
+use common::solution::{self, Solution};
+use day_twelve::{parse_input, DayTwelve, VisitRule};
+
+#[derive(Parser, Debug)]
+#[command(about = DayTwelve::TITLE)]
+struct Args {
+    /// Print every path under this rule instead of solving
+    #[arg(long, value_enum, value_name = "RULE")]
+    list: Option<VisitRule>,
+}
+
+fn main() -> Result<()> {
+    let args = Args::parse();
+    let Some(rule) = args.list else {
+        return solution::run::<DayTwelve>();
+    };
+
+    let raw_input = common::read_input()?;
+    let caves = parse_input(&raw_input)?;
+    let paths = caves.paths(rule);
+    for path in &paths {
+        println!("{}", caves.path_names(path));
+    }
+    println!("\n{} paths", paths.len());
+    Ok(())
+}
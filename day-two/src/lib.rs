@@ -0,0 +1,235 @@
+//! Day 2: Dive!, following the submarine's planned course.
+
+use anyhow::{Error, Result};
+use common::parse::{lines_of, ParseError};
+use common::solution::Solution;
+use itertools::Itertools;
+use std::str::FromStr;
+
+pub struct DayTwo;
+
+impl Solution for DayTwo {
+    const TITLE: &'static str = "Day 2: Dive!";
+    const PART_ONE_QUESTION: &'static str =
+        "What do you get if you multiply your final horizontal position by your final depth?";
+    const PART_TWO_QUESTION: &'static str =
+        "What do you get if you multiply your final horizontal position by your final depth?";
+
+    type Input = Vec<DirectionCommand>;
+    type PartOne = u64;
+    type PartTwo = u64;
+
+    fn parse(raw_input: &str) -> Result<Vec<DirectionCommand>> {
+        Ok(parse_input(raw_input)?)
+    }
+
+    fn part_one(commands: &Vec<DirectionCommand>) -> Result<u64> {
+        Ok(part_one(commands))
+    }
+
+    fn part_two(commands: &Vec<DirectionCommand>) -> Result<u64> {
+        Ok(part_two(commands))
+    }
+}
+
+struct SubPosition {
+    depth: u64,
+    horizontal_position: u64,
+}
+
+/// Which way a command moves the submarine.
+#[derive(Debug, PartialEq)]
+pub enum Direction {
+    Forward,
+    Down,
+    Up,
+}
+
+impl FromStr for Direction {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "forward" => Ok(Direction::Forward),
+            "down" => Ok(Direction::Down),
+            "up" => Ok(Direction::Up),
+            _ => Err(Self::Err::msg(format!(
+                "Failed to parse {} into valid direction",
+                s
+            ))),
+        }
+    }
+}
+
+/// A line of the course, e.g. `forward 5`.
+#[derive(Debug, PartialEq)]
+pub struct DirectionCommand {
+    pub direction: Direction,
+    pub value: u64,
+}
+
+impl FromStr for DirectionCommand {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if let Some((direction, value)) = s.split(" ").collect_tuple() {
+            let parsed_direction = direction.parse::<Direction>()?;
+            let parsed_value = value.parse::<u64>()?;
+            Ok(DirectionCommand {
+                direction: parsed_direction,
+                value: parsed_value,
+            })
+        } else {
+            Err(Self::Err::msg(format!(
+                "Failed to parse into valid DirectionCommand: ${}",
+                s
+            )))
+        }
+    }
+}
+
+/// One command per line.
+pub fn parse_input(raw_input: &str) -> Result<Vec<DirectionCommand>, ParseError> {
+    lines_of::<DirectionCommand>(raw_input)
+}
+
+/// Horizontal position times depth, with down and up changing the depth directly.
+pub fn part_one(commands: &[DirectionCommand]) -> u64 {
+    let mut sub_position = SubPosition {
+        horizontal_position: 0,
+        depth: 0,
+    };
+
+    for command in commands {
+        match command.direction {
+            Direction::Forward => {
+                sub_position.horizontal_position += command.value;
+            }
+            Direction::Down => {
+                sub_position.depth += command.value;
+            }
+            Direction::Up => {
+                sub_position.depth -= command.value;
+            }
+        }
+    }
+
+    sub_position.horizontal_position * sub_position.depth
+}
+
+/// Horizontal position times depth, with down and up changing the aim and forward diving by it.
+pub fn part_two(commands: &[DirectionCommand]) -> u64 {
+    let mut sub_position = SubPosition {
+        horizontal_position: 0,
+        depth: 0,
+    };
+
+    let mut sub_aim = 0;
+
+    for command in commands {
+        match command.direction {
+            Direction::Forward => {
+                sub_position.horizontal_position += command.value;
+                sub_position.depth += command.value * sub_aim;
+            }
+            Direction::Down => {
+                sub_aim += command.value;
+            }
+            Direction::Up => {
+                sub_aim -= command.value;
+            }
+        }
+    }
+
+    sub_position.horizontal_position * sub_position.depth
+}
+
+#[cfg(test)]
+mod tests {
+
+    use crate::{parse_input, part_one, part_two, Direction, DirectionCommand};
+
+    #[test]
+    fn test_parsing() {
+        let input = r#"
+            forward 4
+            down 2
+            up 35
+        "#
+        .trim();
+        let parsed = parse_input(input).expect("failed to parse commands");
+        assert_eq!(
+            parsed,
+            vec![
+                DirectionCommand {
+                    direction: Direction::Forward,
+                    value: 4
+                },
+                DirectionCommand {
+                    direction: Direction::Down,
+                    value: 2
+                },
+                DirectionCommand {
+                    direction: Direction::Up,
+                    value: 35
+                }
+            ]
+        )
+    }
+
+    #[test]
+    fn test_part_one() {
+        let commands = [
+            DirectionCommand {
+                direction: Direction::Forward,
+                value: 432,
+            },
+            DirectionCommand {
+                direction: Direction::Down,
+                value: 210,
+            },
+            DirectionCommand {
+                direction: Direction::Forward,
+                value: 34,
+            },
+            DirectionCommand {
+                direction: Direction::Up,
+                value: 11,
+            },
+            DirectionCommand {
+                direction: Direction::Down,
+                value: 0,
+            },
+        ];
+        let answer = part_one(&commands);
+        assert_eq!(answer, 92734); //calc by hand
+    }
+
+    #[test]
+    fn test_part_two() {
+        let commands = [
+            DirectionCommand {
+                direction: Direction::Forward,
+                value: 432,
+            },
+            DirectionCommand {
+                direction: Direction::Down,
+                value: 210,
+            },
+            DirectionCommand {
+                direction: Direction::Forward,
+                value: 34,
+            },
+            DirectionCommand {
+                direction: Direction::Up,
+                value: 11,
+            },
+            DirectionCommand {
+                direction: Direction::Down,
+                value: 0,
+            },
+        ];
+        let answer = part_two(&commands);
+        assert_eq!(answer, 3327240); //calc by hand
+    }
+}